@@ -0,0 +1,52 @@
+//! Generates the registry of scaffolded solutions that the `all` binary runs in-process.
+//! Every `src/bin/YYYY-DD.rs` file is included as a module, see `template::registry`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<(String, String)> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+            if path.extension()? != "rs" || stem.len() != 7 {
+                return None;
+            }
+            let (year, day) = stem.split_once('-')?;
+            let year = year.parse::<u16>().ok().filter(|y| *y >= 2015)?;
+            let day = day.parse::<u8>().ok().filter(|d| (1..=25).contains(d))?;
+            Some((format!("y{year}_d{day:02}"), path.display().to_string()))
+        })
+        .collect();
+
+    days.sort();
+
+    let mut registry = String::new();
+
+    for (module, path) in &days {
+        registry.push_str(&format!(
+            "#[cfg(not(test))]\n#[path = {path:?}]\nmod {module};\n"
+        ));
+    }
+
+    registry.push_str(
+        "\n/// All scaffolded solutions, ordered by year and day.\n\
+         #[cfg(not(test))]\n\
+         pub static SOLUTIONS: &[&dyn advent_of_code::template::registry::Solution] = &[\n",
+    );
+    for (module, _) in &days {
+        registry.push_str(&format!("    &{module}::Solution,\n"));
+    }
+    registry.push_str("];\n");
+
+    // solution tests run through the individual day binaries.
+    registry.push_str(
+        "\n#[cfg(test)]\n\
+         pub static SOLUTIONS: &[&dyn advent_of_code::template::registry::Solution] = &[];\n",
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, registry).unwrap();
+}
@@ -0,0 +1,301 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use num::{BigInt, BigUint};
+
+use crate::ocr;
+
+/// The answer of one part of a puzzle.
+///
+/// Parts may return any type that implements [`IntoAnswer`]: integers, big integers,
+/// strings and grids of pixels for puzzles that draw their answer.
+///
+/// # Display
+/// This value displays as the text that would be submitted, see [`Answer::pretty`] for console output.
+///
+/// ```
+/// # use advent_of_code::{Answer, IntoAnswer};
+/// let answer = 1234567_u64.into_answer();
+/// assert_eq!(answer.to_string(), "1234567");
+/// assert_eq!(answer.pretty(), "1,234,567")
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    BigInt(BigInt),
+    Text(String),
+    /// Rows of pixels, `true` for a lit pixel.
+    Pixels(Vec<Vec<bool>>),
+}
+
+impl Answer {
+    /// Returns `true` if the answer spans more than one line.
+    #[must_use]
+    pub fn is_multi_line(&self) -> bool {
+        match self {
+            Answer::Int(_) | Answer::BigInt(_) => false,
+            Answer::Text(text) => text.trim().contains('\n'),
+            Answer::Pixels(rows) => rows.len() > 1,
+        }
+    }
+
+    /// Formats the answer for the console: numbers get thousands separators and lit pixels are drawn as blocks.
+    #[must_use]
+    pub fn pretty(&self) -> String {
+        match self {
+            Answer::Int(n) => group_thousands(&n.to_string()),
+            Answer::BigInt(n) => group_thousands(&n.to_string()),
+            Answer::Text(text) => text.clone(),
+            Answer::Pixels(rows) => draw(rows, '█', ' '),
+        }
+    }
+
+    /// Replaces pixels and multi-line text that draw letters with the letters, see [`ocr`].
+    /// Other answers are returned unchanged.
+    #[must_use]
+    pub fn read_letters(self) -> Self {
+        let letters = match &self {
+            Answer::Pixels(rows) => ocr::read_pixels(rows),
+            Answer::Text(text) if self.is_multi_line() => ocr::read(text),
+            _ => None,
+        };
+        letters.map_or(self, Answer::Text)
+    }
+
+    /// Returns the text to submit for this answer.
+    /// Empty and multi-line answers can not be submitted, neither can pixels that have not been read.
+    pub fn to_submission(&self) -> Result<String, NotSubmittable> {
+        match self {
+            Answer::Int(_) | Answer::BigInt(_) => Ok(self.to_string()),
+            Answer::Text(text) if text.trim().is_empty() => Err(NotSubmittable::Empty),
+            Answer::Text(_) if self.is_multi_line() => Err(NotSubmittable::MultiLine),
+            Answer::Text(text) => Ok(text.trim().to_string()),
+            Answer::Pixels(_) => Err(NotSubmittable::Pixels),
+        }
+    }
+
+    /// Returns the text that reports and recorded answers compare: the submission if the answer can be submitted,
+    /// the plain form otherwise.
+    #[must_use]
+    pub fn to_report(&self) -> String {
+        self.to_submission().unwrap_or_else(|_| self.to_string())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::BigInt(n) => write!(f, "{n}"),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Pixels(rows) => f.write_str(&draw(rows, '#', '.')),
+        }
+    }
+}
+
+/// Inserts a comma between every group of three digits, e.g. `-1,234` for `-1234`.
+fn group_thousands(digits: &str) -> String {
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", digits),
+    };
+
+    let mut grouped = String::from(sign);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+fn draw(rows: &[Vec<bool>], lit: char, unlit: char) -> String {
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|&pixel| if pixel { lit } else { unlit })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Conversion of the return value of a part into an [`Answer`].
+pub trait IntoAnswer {
+    fn into_answer(self) -> Answer;
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Answer {
+        self
+    }
+}
+
+macro_rules! impl_into_answer_for_ints {
+    ($($t:ty),*) => {
+        $(
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> Answer {
+                    Answer::Int(self.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_into_answer_for_ints!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl IntoAnswer for usize {
+    fn into_answer(self) -> Answer {
+        (self as u64).into_answer()
+    }
+}
+
+impl IntoAnswer for isize {
+    fn into_answer(self) -> Answer {
+        (self as i64).into_answer()
+    }
+}
+
+impl IntoAnswer for u128 {
+    fn into_answer(self) -> Answer {
+        i128::try_from(self).map_or_else(|_| Answer::BigInt(self.into()), Answer::Int)
+    }
+}
+
+impl IntoAnswer for BigInt {
+    fn into_answer(self) -> Answer {
+        Answer::BigInt(self)
+    }
+}
+
+impl IntoAnswer for BigUint {
+    fn into_answer(self) -> Answer {
+        Answer::BigInt(self.into())
+    }
+}
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> Answer {
+        Answer::Text(self)
+    }
+}
+
+impl IntoAnswer for &str {
+    fn into_answer(self) -> Answer {
+        Answer::Text(self.to_string())
+    }
+}
+
+impl IntoAnswer for Vec<Vec<bool>> {
+    fn into_answer(self) -> Answer {
+        Answer::Pixels(self)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reason why an [`Answer`] can not be submitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotSubmittable {
+    Empty,
+    MultiLine,
+    Pixels,
+}
+
+impl Error for NotSubmittable {}
+
+impl Display for NotSubmittable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotSubmittable::Empty => f.write_str("the answer is empty."),
+            NotSubmittable::MultiLine => f.write_str("the answer spans multiple lines."),
+            NotSubmittable::Pixels => {
+                f.write_str("the answer is a grid of pixels that could not be read as letters.")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, IntoAnswer, NotSubmittable};
+    use num::BigUint;
+
+    #[test]
+    fn formats_numbers() {
+        assert_eq!(7_u32.into_answer().pretty(), "7");
+        assert_eq!(123_456_usize.into_answer().pretty(), "123,456");
+        assert_eq!((-1_234_i64).into_answer().pretty(), "-1,234");
+        assert_eq!(1_234_567_u64.into_answer().to_string(), "1234567");
+
+        let big = BigUint::from(u128::MAX) * 10_u32;
+        assert_eq!(
+            big.into_answer().pretty(),
+            "3,402,823,669,209,384,634,633,746,074,317,682,114,550"
+        );
+        assert!(matches!(u128::MAX.into_answer(), Answer::BigInt(_)));
+    }
+
+    #[test]
+    fn formats_pixels() {
+        let answer = vec![vec![true, false], vec![false, true]].into_answer();
+        assert_eq!(answer.to_string(), "#.\n.#");
+        assert_eq!(answer.pretty(), "█ \n █");
+        assert!(answer.is_multi_line());
+    }
+
+    #[test]
+    fn checks_submissions() {
+        assert_eq!(42_u8.into_answer().to_submission(), Ok("42".into()));
+        assert_eq!("ABC\n".into_answer().to_submission(), Ok("ABC".into()));
+        assert_eq!(
+            " ".into_answer().to_submission(),
+            Err(NotSubmittable::Empty)
+        );
+        assert_eq!(
+            "A\nB".into_answer().to_submission(),
+            Err(NotSubmittable::MultiLine)
+        );
+        assert_eq!(
+            vec![vec![true]].into_answer().to_submission(),
+            Err(NotSubmittable::Pixels)
+        );
+    }
+
+    #[test]
+    fn reports_submitted_text() {
+        assert_eq!("ABC\n".into_answer().to_report(), "ABC");
+        assert_eq!(" 42 ".into_answer().to_report(), "42");
+        assert_eq!("A\nB".into_answer().to_report(), "A\nB");
+        assert_eq!(1_234_u32.into_answer().to_report(), "1234");
+    }
+
+    #[test]
+    fn reads_letters() {
+        let art = "#...\n#...\n#...\n#...\n#...\n####\n";
+        assert_eq!(art.into_answer().read_letters(), Answer::Text("L".into()));
+
+        let pixels: Vec<Vec<bool>> = art
+            .lines()
+            .map(|line| line.chars().map(|c| c == '#').collect())
+            .collect();
+        assert_eq!(
+            pixels.into_answer().read_letters(),
+            Answer::Text("L".into())
+        );
+
+        assert_eq!(42_u32.into_answer().read_letters(), Answer::Int(42));
+        assert_eq!(
+            "#\n#".into_answer().read_letters(),
+            Answer::Text("#\n#".into())
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
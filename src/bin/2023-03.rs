@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use advent_of_code::grid::{Grid, Pos};
+
+advent_of_code::solution!(2023, 3);
+
+/// A number in the schematic with the positions of its digits.
+struct Number {
+    value: u32,
+    positions: Vec<Pos>,
+}
+
+fn read_numbers(grid: &Grid<char>) -> Vec<Number> {
+    let mut numbers = Vec::new();
+    for (row_idx, row) in grid.rows().enumerate() {
+        let mut col_idx = 0;
+        while col_idx < row.len() {
+            let len = row[col_idx..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            if len == 0 {
+                col_idx += 1;
+                continue;
+            }
+            // digit runs that overflow are skipped.
+            if let Ok(value) = row[col_idx..col_idx + len]
+                .iter()
+                .collect::<String>()
+                .parse()
+            {
+                numbers.push(Number {
+                    value,
+                    positions: (col_idx..col_idx + len).map(|x| (row_idx, x)).collect(),
+                });
+            }
+            col_idx += len;
+        }
+    }
+    numbers
+}
+
+/// Returns the positions adjacent to a number, without the number itself.
+fn adjacent_positions<'a>(
+    grid: &'a Grid<char>,
+    number: &'a Number,
+) -> impl Iterator<Item = Pos> + 'a {
+    let mut adjacent: Vec<Pos> = number
+        .positions
+        .iter()
+        .flat_map(|pos| grid.neighbours8(*pos))
+        .filter(|pos| !number.positions.contains(pos))
+        .collect();
+    adjacent.sort_unstable();
+    adjacent.dedup();
+    adjacent.into_iter()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid: Grid<char> = input.parse().unwrap();
+
+    Some(
+        read_numbers(&grid)
+            .iter()
+            .filter(|number| {
+                adjacent_positions(&grid, number).any(|pos| {
+                    let c = grid[pos];
+                    c != '.' && !c.is_ascii_digit()
+                })
+            })
+            .map(|number| number.value)
+            .sum(),
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid: Grid<char> = input.parse().unwrap();
+    let mut gears: HashMap<Pos, Vec<u32>> = HashMap::new();
+
+    for number in read_numbers(&grid) {
+        for pos in adjacent_positions(&grid, &number).filter(|pos| grid[*pos] == '*') {
+            gears.entry(pos).or_default().push(number.value);
+        }
+    }
+
+    Some(
+        gears
+            .values()
+            .filter(|v| v.len() == 2)
+            .map(|v| v[0] * v[1])
+            .sum(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(4361));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(467835));
+    }
+
+    #[test]
+    fn test_overflowing_numbers() {
+        assert_eq!(part_one("99999999999*12\n"), Some(12));
+    }
+}
@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7);
 
 fn map_card_to_value(c: char) -> Option<usize> {
     match c {
@@ -18,11 +18,11 @@ fn map_card_to_value(c: char) -> Option<usize> {
         'K' => Some(11),
         'A' => Some(12),
 
-
-        _ => None,
-    }
-}
-
+
+        _ => None,
+    }
+}
+
 fn map_value_to_card(v: usize) -> Option<char> {
     match v {
         0 => Some('2'),
@@ -239,14 +239,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(6440));
 
-    }
-
+    }
+
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(5905));
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2023, 8);
 
 type Directions = Vec<usize>;
 type Nodes<'a> = HashMap<&'a str, [&'a str; 2]>;
@@ -16,7 +16,7 @@ fn parse_directions(input: &str) -> Directions {
         .collect()
 }
 
-fn parse_nodes(input: &str) -> Nodes {
+fn parse_nodes(input: &str) -> Nodes<'_> {
     input
         .lines()
         .filter_map(|line| {
@@ -30,7 +30,7 @@ fn parse_nodes(input: &str) -> Nodes {
         .collect()
 }
 
-fn parse_input(input: &str) -> Option<(Directions, Nodes)> {
+fn parse_input(input: &str) -> Option<(Directions, Nodes<'_>)> {
     input
         .split_once("\n\n")
         .map(|(direction, nodes)| (parse_directions(direction), parse_nodes(nodes)))
@@ -75,14 +75,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2));
     }
 
     #[test]
     fn another_test_part_one() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", PUZZLE, 2,
         ));
         assert_eq!(result, Some(6));
     }
@@ -90,7 +90,7 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", PUZZLE, 3,
         ));
         assert_eq!(result, Some(6));
     }
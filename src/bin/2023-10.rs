@@ -0,0 +1,133 @@
+use advent_of_code::geom::Direction4::{self, Down, Left, Right, Up};
+use advent_of_code::grid::{Grid, Pos};
+
+advent_of_code::solution!(2023, 10, parse_grid);
+
+/// Returns the bit of a direction in the bitmask of the directions a pipe connects.
+const fn mask(direction: Direction4) -> u8 {
+    1 << direction as u8
+}
+
+const ALL_MASK: u8 = mask(Up) | mask(Right) | mask(Down) | mask(Left);
+
+fn encode_pipe_directions(c: char) -> u8 {
+    match c {
+        '|' => mask(Up) | mask(Down),
+        '-' => mask(Left) | mask(Right),
+        'L' => mask(Up) | mask(Right),
+        'J' => mask(Up) | mask(Left),
+        '7' => mask(Down) | mask(Left),
+        'F' => mask(Down) | mask(Right),
+        'S' => ALL_MASK,
+        _ => 0,
+    }
+}
+
+fn parse_grid(input: &str) -> (Grid<u8>, Pos) {
+    let mut grid = Grid::parse_with(input, encode_pipe_directions).unwrap();
+    let starting_position = grid.find(&ALL_MASK).unwrap();
+
+    // keep only the directions of the start that connect to the neighbouring pipes.
+    for direction in Direction4::ALL {
+        if grid
+            .step(starting_position, direction.offset())
+            .is_some_and(|pos| grid[pos] & mask(direction.opposite()) == 0)
+        {
+            grid[starting_position] &= !mask(direction);
+        }
+    }
+    (grid, starting_position)
+}
+
+fn find_loop(grid: &Grid<u8>, starting_position: Pos) -> Vec<Pos> {
+    let mut loop_cells = Vec::new();
+    let mut came_from = None;
+    let mut curr_pos = starting_position;
+    loop {
+        loop_cells.push(curr_pos);
+        let direction = [Down, Right, Up, Left]
+            .into_iter()
+            .find(|&direction| {
+                grid[curr_pos] & mask(direction) != 0 && came_from != Some(direction)
+            })
+            .expect("No direction available");
+        came_from = Some(direction.opposite());
+        let next_pos = grid.step(curr_pos, direction.offset()).unwrap();
+
+        if next_pos == starting_position {
+            break;
+        } else {
+            curr_pos = next_pos;
+        }
+    }
+    loop_cells
+}
+
+pub fn part_one((grid, starting_position): &(Grid<u8>, Pos)) -> Option<u32> {
+    let loop_cells = find_loop(grid, *starting_position);
+
+    Some(loop_cells.len().div_ceil(2) as u32)
+}
+
+pub fn part_two((grid, starting_position): &(Grid<u8>, Pos)) -> Option<u32> {
+    let loop_cells = find_loop(grid, *starting_position);
+
+    let vertices: Vec<_> = loop_cells
+        .iter()
+        .filter(|&&pos| grid[pos] != mask(Up) | mask(Down) && grid[pos] != mask(Left) | mask(Right))
+        .map(|&(row_idx, col_idx)| (col_idx, grid.height() - row_idx))
+        .collect();
+    let mut two_area = 0;
+    for i in 0..vertices.len() {
+        let next_i = (i + 1) % vertices.len();
+        two_area += (vertices[i].0 * vertices[next_i].1) as isize
+            - (vertices[i].1 * vertices[next_i].0) as isize;
+    }
+    let inner_cells_count = (two_area.abs() - loop_cells.len() as isize) / 2 + 1;
+    Some(inner_cells_count as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse_grid(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
+        assert_eq!(result, Some(4));
+    }
+
+    #[test]
+    fn test_part_one_2() {
+        let result = part_one(&parse_grid(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 2,
+        )));
+        assert_eq!(result, Some(8))
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse_grid(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 3,
+        )));
+        assert_eq!(result, Some(4));
+    }
+
+    #[test]
+    fn test_part_two_2() {
+        let result = part_two(&parse_grid(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 4,
+        )));
+        assert_eq!(result, Some(8));
+    }
+
+    #[test]
+    fn test_part_two_3() {
+        let result = part_two(&parse_grid(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 5,
+        )));
+        assert_eq!(result, Some(10));
+    }
+}
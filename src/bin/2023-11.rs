@@ -0,0 +1,73 @@
+use advent_of_code::grid::Grid;
+use itertools::Itertools;
+
+advent_of_code::solution!(2023, 11, parse_galaxy);
+
+fn parse_galaxy(input: &str) -> Grid<char> {
+    input.parse().unwrap()
+}
+
+/// Returns for every index how many empty lines come before it, where `is_empty` tells if a line is empty.
+fn expansion_offsets(len: usize, is_empty: impl Fn(usize) -> bool) -> Vec<usize> {
+    (0..len)
+        .scan(0, |offset, idx| {
+            let current = *offset;
+            if is_empty(idx) {
+                *offset += 1;
+            }
+            Some(current)
+        })
+        .collect()
+}
+
+fn sum_shortest_paths(galaxy: &Grid<char>, expansion: usize) -> u64 {
+    let row_idx_offset = expansion_offsets(galaxy.height(), |row_idx| {
+        galaxy.row(row_idx).iter().all(|c| *c != '#')
+    });
+    let col_idx_offset = expansion_offsets(galaxy.width(), |col_idx| {
+        galaxy.column(col_idx).all(|c| *c != '#')
+    });
+
+    galaxy
+        .iter()
+        .filter(|(_, c)| **c == '#')
+        .map(|((row_idx, col_idx), _)| {
+            (
+                row_idx + row_idx_offset[row_idx] * (expansion - 1),
+                col_idx + col_idx_offset[col_idx] * (expansion - 1),
+            )
+        })
+        .combinations(2)
+        .map(|p| p[0].0.abs_diff(p[1].0) + p[0].1.abs_diff(p[1].1))
+        .map(|v| v as u64)
+        .sum()
+}
+
+pub fn part_one(galaxy: &Grid<char>) -> Option<u64> {
+    Some(sum_shortest_paths(galaxy, 2))
+}
+
+pub fn part_two(galaxy: &Grid<char>) -> Option<u64> {
+    Some(sum_shortest_paths(galaxy, 1000000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse_galaxy(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
+        assert_eq!(result, Some(374));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse_galaxy(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
+        assert_eq!(result, Some(82000210));
+    }
+}
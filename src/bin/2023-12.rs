@@ -1,4 +1,4 @@
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12);
 
 #[derive(Clone, Copy, PartialEq)]
 enum Spring {
@@ -36,7 +36,7 @@ impl Outcome {
 }
 
 fn place(len: usize, springs: &[Spring], lens: &[usize], m: &mut Vec<Vec<Outcome>>) -> Outcome {
-    if len > springs.len() || springs[..len].iter().any(|spring| *spring == Spring::Ok) {
+    if len > springs.len() || springs[..len].contains(&Spring::Ok) {
         Outcome::Invalid
     } else if len == springs.len() {
         arrangements(&springs[len..], lens, m)
@@ -121,13 +121,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(525152));
     }
 }
@@ -0,0 +1,97 @@
+use advent_of_code::grid::Grid;
+
+advent_of_code::solution!(2023, 13);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Ash,
+    Rock,
+}
+
+impl From<char> for Position {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Ash,
+            '#' => Self::Rock,
+            _ => panic!("unknown position value"),
+        }
+    }
+}
+
+enum MirrorLine {
+    NotFound,
+    Vertical { total_left_cols: usize },
+    Horizontal { total_top_rows: usize },
+}
+
+/// Returns the number of rows above the horizontal line that mirrors the pattern with exactly `smudges_count` differences.
+fn find_horizontal_line(pattern: &Grid<Position>, smudges_count: usize) -> Option<usize> {
+    (1..pattern.height()).find(|&total_top_rows| {
+        let top_rows = (0..total_top_rows).rev();
+        let bottom_rows = total_top_rows..pattern.height();
+        let total_diff: usize = top_rows
+            .zip(bottom_rows)
+            .map(|(top_idx, bottom_idx)| {
+                pattern
+                    .row(top_idx)
+                    .iter()
+                    .zip(pattern.row(bottom_idx))
+                    .filter(|(top, bottom)| top != bottom)
+                    .count()
+            })
+            .sum();
+        total_diff == smudges_count
+    })
+}
+
+fn find_mirror_line(pattern: &Grid<Position>, smudges_count: usize) -> MirrorLine {
+    if let Some(total_left_cols) = find_horizontal_line(&pattern.transpose(), smudges_count) {
+        return MirrorLine::Vertical { total_left_cols };
+    }
+
+    if let Some(total_top_rows) = find_horizontal_line(pattern, smudges_count) {
+        return MirrorLine::Horizontal { total_top_rows };
+    }
+
+    MirrorLine::NotFound
+}
+
+fn summarize(input: &str, smudges_count: usize) -> u32 {
+    let summary = input
+        .split("\n\n")
+        .map(|input| {
+            let pattern: Grid<Position> = input.parse().unwrap();
+            match find_mirror_line(&pattern, smudges_count) {
+                MirrorLine::NotFound => 0,
+                MirrorLine::Vertical { total_left_cols } => total_left_cols,
+                MirrorLine::Horizontal { total_top_rows } => 100 * total_top_rows,
+            }
+        })
+        .sum::<usize>();
+    summary as u32
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(summarize(input, 0))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(summarize(input, 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(405));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(400));
+    }
+}
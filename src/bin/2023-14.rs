@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use advent_of_code::grid::Grid;
+
+advent_of_code::solution!(2023, 14, parse_dish);
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Position {
+    RoundedRock,
+    CubeRock,
+    Space,
+}
+
+impl From<char> for Position {
+    fn from(value: char) -> Self {
+        match value {
+            'O' => Self::RoundedRock,
+            '#' => Self::CubeRock,
+            '.' => Self::Space,
+            _ => panic!("unknown character"),
+        }
+    }
+}
+
+impl From<Position> for char {
+    fn from(value: Position) -> Self {
+        match value {
+            Position::Space => '.',
+            Position::CubeRock => '#',
+            Position::RoundedRock => 'O',
+        }
+    }
+}
+
+fn tilt_dish_northside(dish: &mut Grid<Position>) {
+    for col_idx in 0..dish.width() {
+        let mut free_row_idx = 0;
+        for row_idx in 0..dish.height() {
+            match dish[(row_idx, col_idx)] {
+                Position::CubeRock => free_row_idx = row_idx + 1,
+                Position::RoundedRock => {
+                    dish[(row_idx, col_idx)] = Position::Space;
+                    dish[(free_row_idx, col_idx)] = Position::RoundedRock;
+                    free_row_idx += 1;
+                }
+                Position::Space => {}
+            }
+        }
+    }
+}
+
+/// Tilts the dish north, west, south and east. After each tilt the dish is rotated clockwise,
+/// so that the next side faces north.
+fn spin_dish(dish: &Grid<Position>) -> Grid<Position> {
+    let mut dish = dish.clone();
+    for _ in 0..4 {
+        tilt_dish_northside(&mut dish);
+        dish = dish.rotate_clockwise();
+    }
+    dish
+}
+
+fn calculate_load_northside(dish: &Grid<Position>) -> u32 {
+    dish.iter()
+        .filter(|(_, position)| **position == Position::RoundedRock)
+        .map(|((row_idx, _), _)| (dish.height() - row_idx) as u32)
+        .sum()
+}
+
+fn parse_dish(input: &str) -> Grid<Position> {
+    input.parse().unwrap()
+}
+
+pub fn part_one(dish: &Grid<Position>) -> Option<u32> {
+    let mut dish = dish.clone();
+    tilt_dish_northside(&mut dish);
+    Some(calculate_load_northside(&dish))
+}
+
+pub fn part_two(dish: &Grid<Position>) -> Option<u32> {
+    let mut dish = dish.clone();
+    let mut cache = HashMap::new();
+    cache.insert(dish.clone(), 0);
+
+    let total_cycles = 1_000_000_000;
+    for i in 0..total_cycles {
+        dish = spin_dish(&dish);
+        if let Some(prev_i) = cache.get(&dish) {
+            let cycle = i + 1 - prev_i;
+            let remaining = (total_cycles - i - 1) % cycle;
+            for _ in 0..remaining {
+                dish = spin_dish(&dish);
+            }
+            break;
+        }
+        cache.insert(dish.clone(), i + 1);
+    }
+    Some(calculate_load_northside(&dish))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse_dish(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
+        assert_eq!(result, Some(136));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse_dish(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
+        assert_eq!(result, Some(64));
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use advent_of_code::geom::Direction4;
+use advent_of_code::grid::{Grid, Pos};
+
+advent_of_code::solution!(2023, 16);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Beam {
+    pos: Pos,
+    direction: Direction4,
+}
+
+enum BeamUpdate {
+    NoChange,
+    Reflected(Direction4),
+    Splited(Direction4, Direction4),
+}
+
+impl Beam {
+    fn interact(self, object: char) -> BeamUpdate {
+        let direction = self.direction;
+        match object {
+            '.' => BeamUpdate::NoChange,
+            '/' if direction.is_vertical() => BeamUpdate::Reflected(direction.turn_right()),
+            '/' => BeamUpdate::Reflected(direction.turn_left()),
+            '\\' if direction.is_vertical() => BeamUpdate::Reflected(direction.turn_left()),
+            '\\' => BeamUpdate::Reflected(direction.turn_right()),
+            '|' if direction.is_horizontal() => {
+                BeamUpdate::Splited(direction.turn_left(), direction.turn_right())
+            }
+            '-' if direction.is_vertical() => {
+                BeamUpdate::Splited(direction.turn_left(), direction.turn_right())
+            }
+            '|' | '-' => BeamUpdate::NoChange,
+            c => panic!("unknown object: {}", c),
+        }
+    }
+
+    fn bounces(&mut self, grid: &Grid<char>) -> bool {
+        match grid.step(self.pos, self.direction.offset()) {
+            Some(pos) => {
+                self.pos = pos;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn count_energized_tiles(grid: &Grid<char>, starter: Beam) -> u32 {
+    let mut energized_tiles = HashMap::new();
+
+    let mut beams = vec![starter];
+    while let Some(mut beam) = beams.pop() {
+        loop {
+            let entry = energized_tiles.entry(beam.pos).or_insert_with(Vec::new);
+
+            if entry.contains(&beam.direction) {
+                break;
+            }
+
+            entry.push(beam.direction);
+
+            match beam.interact(grid[beam.pos]) {
+                BeamUpdate::NoChange => {}
+                BeamUpdate::Reflected(direction) => beam.direction = direction,
+                BeamUpdate::Splited(direction, another_direction) => {
+                    let mut another_beam = Beam {
+                        direction: another_direction,
+                        ..beam
+                    };
+
+                    if another_beam.bounces(grid) {
+                        beams.push(another_beam);
+                    }
+
+                    beam.direction = direction;
+                }
+            }
+
+            if !beam.bounces(grid) {
+                break;
+            }
+        }
+    }
+
+    energized_tiles.len() as u32
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid: Grid<char> = input.parse().unwrap();
+    let energized_tiles = count_energized_tiles(
+        &grid,
+        Beam {
+            pos: (0, 0),
+            direction: Direction4::Right,
+        },
+    );
+    Some(energized_tiles)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid: Grid<char> = input.parse().unwrap();
+    let mut positions = vec![];
+    for pos in grid.positions() {
+        let (row_idx, col_idx) = pos;
+        if row_idx == 0 {
+            positions.push(Beam {
+                pos,
+                direction: Direction4::Down,
+            });
+        } else if row_idx == grid.height() - 1 {
+            positions.push(Beam {
+                pos,
+                direction: Direction4::Up,
+            });
+        }
+
+        if col_idx == 0 {
+            positions.push(Beam {
+                pos,
+                direction: Direction4::Right,
+            });
+        } else if col_idx == grid.width() - 1 {
+            positions.push(Beam {
+                pos,
+                direction: Direction4::Left,
+            });
+        }
+    }
+
+    positions
+        .into_iter()
+        .map(|pos| count_energized_tiles(&grid, pos))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(46));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(51));
+    }
+}
@@ -0,0 +1,109 @@
+use std::num::NonZeroUsize;
+
+use advent_of_code::geom::Direction4;
+use advent_of_code::grid::{Grid, Pos};
+use advent_of_code::search::dijkstra_buckets;
+
+advent_of_code::solution!(2023, 17);
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+struct Node {
+    pos: Pos,
+    /// The direction of the last move, `None` at the start.
+    direction: Option<Direction4>,
+    direction_count: usize,
+}
+
+fn get_neighbors(
+    node: &Node,
+    grid: &Grid<usize>,
+    min_blocks: NonZeroUsize,
+    max_blocks: NonZeroUsize,
+) -> Vec<Node> {
+    let min_blocks = min_blocks.get();
+    let max_blocks = max_blocks.get();
+
+    Direction4::ALL
+        .into_iter()
+        .filter(|direction| node.direction != Some(direction.opposite()))
+        .filter_map(|direction| {
+            let pos = grid.step(node.pos, direction.offset())?;
+            let is_straight = node.direction == Some(direction);
+            let direction_count = if is_straight {
+                node.direction_count + 1
+            } else {
+                1
+            };
+            let is_allowed = node.direction.is_none()
+                || ((is_straight || node.direction_count >= min_blocks)
+                    && direction_count <= max_blocks);
+
+            is_allowed.then_some(Node {
+                pos,
+                direction: Some(direction),
+                direction_count,
+            })
+        })
+        .collect()
+}
+
+/// Returns the least heat lost on the way from the top left to the bottom right block.
+fn min_heat_loss(grid: &Grid<usize>, min_blocks: usize, max_blocks: usize) -> Option<usize> {
+    let min_blocks = NonZeroUsize::new(min_blocks).unwrap();
+    let max_blocks = NonZeroUsize::new(max_blocks).unwrap();
+
+    let start = Node {
+        pos: (0, 0),
+        direction: None,
+        direction_count: 0,
+    };
+    let end_pos = (grid.height() - 1, grid.width() - 1);
+
+    let path = dijkstra_buckets(
+        start,
+        |node| {
+            get_neighbors(node, grid, min_blocks, max_blocks)
+                .into_iter()
+                .map(|node| (node, grid[node.pos]))
+        },
+        |node| node.pos == end_pos && node.direction_count >= min_blocks.get(),
+    )?;
+    Some(path.cost)
+}
+
+fn parse_grid(input: &str) -> Grid<usize> {
+    Grid::parse_with(input, |c| c.to_digit(10).unwrap() as usize).unwrap()
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    min_heat_loss(&parse_grid(input), 1, 3)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    min_heat_loss(&parse_grid(input), 4, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(102));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(94));
+    }
+
+    #[test]
+    fn another_test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 2,
+        ));
+        assert_eq!(result, Some(71));
+    }
+}
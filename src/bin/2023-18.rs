@@ -0,0 +1,76 @@
+use advent_of_code::geom::{Direction4, Point};
+
+advent_of_code::solution!(2023, 18);
+
+/// Returns the number of cubes dug out by the plan: the area of the trench polygon, with the trench itself.
+fn lagoon_size(plan: impl Iterator<Item = (Direction4, isize)>) -> usize {
+    let mut curr_point = Point::new(0, 0);
+    let mut prev_point = Point::new(0, 0);
+    let mut outer_points = 0;
+    let mut area = 0;
+
+    for (direction, length) in plan {
+        curr_point += direction.unit() * length;
+
+        area += curr_point.y * prev_point.x;
+        area -= curr_point.x * prev_point.y;
+        outer_points += length as usize;
+        prev_point = curr_point;
+    }
+
+    area.unsigned_abs() / 2 + outer_points / 2 + 1
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let directions = input.lines().map(|line| {
+        let mut tokens = line.split_whitespace();
+        let direction = tokens.next().unwrap().parse().unwrap();
+        let length = tokens.next().unwrap().parse::<isize>().unwrap();
+        (direction, length)
+    });
+
+    Some(lagoon_size(directions))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let directions = input.lines().map(|line| {
+        let tokens = line.split_whitespace();
+        let mut length_direction_encoded = tokens
+            .last()
+            .unwrap()
+            .chars()
+            .filter(|c| c.is_alphanumeric());
+        let mut hexadecimal_length = String::new();
+        for _ in 0..5 {
+            hexadecimal_length.push(length_direction_encoded.next().unwrap());
+        }
+        let length = isize::from_str_radix(&hexadecimal_length, 16).unwrap();
+        let direction = match length_direction_encoded.next().unwrap() {
+            '0' => Direction4::Right,
+            '1' => Direction4::Down,
+            '2' => Direction4::Left,
+            '3' => Direction4::Up,
+            c => panic!("unknown encoded direction: {}", c),
+        };
+        (direction, length)
+    });
+
+    Some(lagoon_size(directions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(62));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(952408144115));
+    }
+}
@@ -1,14 +1,12 @@
 use std::collections::HashMap;
 
-use itertools::iproduct;
-
-advent_of_code::solution!(19);
+advent_of_code::solution!(2023, 19, parse_input);
 
 type Rating = Vec<u64>; // X M A S
-struct Branch<'a>(&'a str, Option<(usize, u8, u64)>);
+pub struct Branch<'a>(&'a str, Option<(usize, u8, u64)>);
 type Workflow<'a> = Vec<Branch<'a>>;
 
-fn parse_input(input: &str) -> (HashMap<&str, Workflow>, Vec<Rating>) {
+fn parse_input(input: &str) -> (HashMap<&str, Workflow<'_>>, Vec<Rating>) {
     let (workflows, ratings) = input.split_once("\n\n").unwrap();
     let workflows = workflows
         .lines()
@@ -83,13 +81,12 @@ fn process_workflow(
     total
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (workflows, ratings) = parse_input(input);
+pub fn part_one((workflows, ratings): &(HashMap<&str, Workflow<'_>>, Vec<Rating>)) -> Option<u64> {
     let total_ratings = ratings
-        .into_iter()
+        .iter()
         .filter(|rating| {
             process_workflow(
-                &workflows,
+                workflows,
                 "in",
                 [
                     (rating[0], rating[0] + 1),
@@ -104,10 +101,9 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(total_ratings)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (workflows, _) = parse_input(input);
+pub fn part_two((workflows, _): &(HashMap<&str, Workflow<'_>>, Vec<Rating>)) -> Option<u64> {
     let total_ratings = process_workflow(
-        &workflows,
+        workflows,
         "in",
         [(1, 4001), (1, 4001), (1, 4001), (1, 4001)],
     );
@@ -120,13 +116,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(19114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(167409079868000));
     }
 }
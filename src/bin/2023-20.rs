@@ -1,16 +1,16 @@
 use num::Integer;
 use std::collections::{HashMap, HashSet, VecDeque};
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(2023, 20, parse_modules, budget = "5s");
 
 #[derive(Debug, Clone, Copy)]
-enum Signal {
+pub enum Signal {
     LowPulse,
     HighPulse,
 }
 
-#[derive(Debug)]
-enum Module {
+#[derive(Debug, Clone)]
+pub enum Module {
     Broadcaster(Vec<String>),
     FlipFlop {
         online: bool,
@@ -83,7 +83,7 @@ fn parse_module(value: &str) -> (&str, Module) {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+fn parse_modules(input: &str) -> HashMap<&str, Module> {
     let mut modules: HashMap<_, _> = input.lines().map(parse_module).collect();
     let mut conjunction_inputs_map = HashMap::new();
     for (name, module) in modules.iter() {
@@ -114,6 +114,12 @@ pub fn part_one(input: &str) -> Option<u64> {
         }
     }
 
+    modules
+}
+
+pub fn part_one(modules: &HashMap<&str, Module>) -> Option<u64> {
+    let mut modules = modules.clone();
+
     let mut low_pulse_counts = 0;
     let mut high_pulse_counts = 0;
     let mut cache = HashMap::new();
@@ -173,36 +179,8 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(low_pulse_counts * high_pulse_counts)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut modules: HashMap<_, _> = input.lines().map(parse_module).collect();
-    let mut conjunction_inputs_map = HashMap::new();
-    for (name, module) in modules.iter() {
-        let outputs = match module {
-            Module::Broadcaster(output)
-            | Module::FlipFlop {
-                outputs: output, ..
-            }
-            | Module::Conjunction {
-                outputs: output, ..
-            } => output,
-        };
-
-        for n in outputs {
-            if let Some(Module::Conjunction { .. }) = modules.get(n.as_str()) {
-                let entry = conjunction_inputs_map
-                    .entry(n.to_owned())
-                    .or_insert_with(HashMap::new);
-                entry.insert(name.to_string(), Signal::LowPulse);
-            }
-        }
-    }
-    for (name, conjunction_inputs) in conjunction_inputs_map {
-        if let Some(Module::Conjunction { inputs, outputs: _ }) = modules.get_mut(name.as_str()) {
-            *inputs = conjunction_inputs;
-        } else {
-            panic!("invalid code");
-        }
-    }
+pub fn part_two(modules: &HashMap<&str, Module>) -> Option<u64> {
+    let mut modules = modules.clone();
 
     let mut result = 1;
     let mut components_name: HashSet<_> = ["lh", "fk", "ff", "mm"].into();
@@ -247,17 +225,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
+        let result = part_one(&parse_modules(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 1,
+        )));
         assert_eq!(result, Some(32000000));
     }
 
     #[test]
     fn another_test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
+        let result = part_one(&parse_modules(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 2,
+        )));
         assert_eq!(result, Some(11687500));
     }
 }
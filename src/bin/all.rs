@@ -0,0 +1,6 @@
+//! Runs every scaffolded solution in a single process. Invoked by `cargo all`.
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+fn main() {
+    advent_of_code::template::commands::all::run_in_process(SOLUTIONS);
+}
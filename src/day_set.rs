@@ -0,0 +1,206 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{all_days, day, Day};
+
+/// A set of days of advent.
+///
+/// # Parsing
+/// Sets are written as comma separated days and ranges of days. Ranges may be open,
+/// e.g. `20-` includes every day from the 20th to the 25th.
+///
+/// ```
+/// # use advent_of_code::DaySet;
+/// let days: DaySet = "1-10,15,20-".parse().unwrap();
+/// assert_eq!(days.len(), 17);
+/// assert_eq!(days.to_string(), "1-10,15,20-25")
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DaySet(u32);
+
+impl DaySet {
+    /// Creates a set without any days.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Creates a set of every day from the 1st to the 25th.
+    pub fn all() -> Self {
+        all_days().collect()
+    }
+
+    /// Adds a day to the set.
+    pub fn insert(&mut self, day: Day) {
+        self.0 |= 1 << day.into_inner();
+    }
+
+    /// Returns `true` if the set includes the day.
+    pub fn contains(&self, day: Day) -> bool {
+        self.0 & (1 << day.into_inner()) != 0
+    }
+
+    /// Returns `true` if the set does not include any day.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of days in the set.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns the days that are in this set, but not in `other`.
+    #[must_use]
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns the days that are in both sets.
+    #[must_use]
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Iterates over the days of the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Day> + '_ {
+        all_days().filter(|day| self.contains(*day))
+    }
+}
+
+impl FromIterator<Day> for DaySet {
+    fn from_iter<T: IntoIterator<Item = Day>>(iter: T) -> Self {
+        let mut set = Self::empty();
+        iter.into_iter().for_each(|day| set.insert(day));
+        set
+    }
+}
+
+impl Display for DaySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ranges: Vec<(u8, u8)> = vec![];
+
+        for day in self.iter().map(Day::into_inner) {
+            match ranges.last_mut() {
+                Some((_, end)) if *end + 1 == day => *end = day,
+                _ => ranges.push((day, day)),
+            }
+        }
+
+        let ranges: Vec<String> = ranges
+            .into_iter()
+            .map(|(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{start}-{end}")
+                }
+            })
+            .collect();
+
+        f.write_str(&ranges.join(","))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self::empty();
+
+        for item in s.split(',').map(str::trim) {
+            let (start, end) = match item.split_once('-') {
+                Some((start, end)) => (
+                    parse_bound(start)?.unwrap_or(day!(1)),
+                    parse_bound(end)?.unwrap_or(day!(25)),
+                ),
+                None => {
+                    let day = item.parse().map_err(|_| DaySetFromStrError)?;
+                    (day, day)
+                }
+            };
+
+            if start > end {
+                return Err(DaySetFromStrError);
+            }
+
+            all_days()
+                .filter(|day| (start..=end).contains(day))
+                .for_each(|day| set.insert(day));
+        }
+
+        Ok(set)
+    }
+}
+
+/// Parses one side of a range, an empty string is an open bound.
+fn parse_bound(s: &str) -> Result<Option<Day>, DaySetFromStrError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(None);
+    }
+    s.parse().map(Some).map_err(|_| DaySetFromStrError)
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError;
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting days between 1 and 25 like `1-10,15,20-`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DaySet;
+    use crate::day;
+
+    #[test]
+    fn parses_day_sets() {
+        let days: DaySet = "1-10, 15,20-".parse().unwrap();
+        assert_eq!(days.len(), 17);
+        assert!(days.contains(day!(10)));
+        assert!(!days.contains(day!(11)));
+        assert!(days.contains(day!(25)));
+        assert_eq!(days.to_string(), "1-10,15,20-25");
+
+        assert_eq!("-3".parse::<DaySet>().unwrap().to_string(), "1-3");
+        assert_eq!("7".parse::<DaySet>().unwrap().to_string(), "7");
+        assert_eq!("-".parse::<DaySet>().unwrap(), DaySet::all());
+    }
+
+    #[test]
+    fn rejects_invalid_day_sets() {
+        assert!("".parse::<DaySet>().is_err());
+        assert!("0".parse::<DaySet>().is_err());
+        assert!("26-".parse::<DaySet>().is_err());
+        assert!("10-1".parse::<DaySet>().is_err());
+        assert!("1,,2".parse::<DaySet>().is_err());
+        assert!("one".parse::<DaySet>().is_err());
+    }
+
+    #[test]
+    fn combines_day_sets() {
+        let days: DaySet = "1-5".parse().unwrap();
+        let excluded: DaySet = "2,4".parse().unwrap();
+
+        assert_eq!(days.difference(excluded).to_string(), "1,3,5");
+        assert_eq!(days.intersection(excluded), excluded);
+        assert_eq!(
+            days.iter().map(|d| d.into_inner()).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5]
+        );
+        assert!(DaySet::empty().is_empty());
+        assert_eq!(DaySet::all().len(), 25);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
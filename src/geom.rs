@@ -0,0 +1,364 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use num::Signed;
+
+use crate::grid::Pos;
+
+/// A point in the plane. `y` grows downwards, as rows do in a [`Grid`](crate::grid::Grid).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the Manhattan distance to another point, also for unsigned coordinates.
+    pub fn manhattan(self, other: Self) -> T
+    where
+        T: PartialOrd + Sub<Output = T> + Add<Output = T>,
+    {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+}
+
+impl Point<usize> {
+    /// Moves from the point by a `(row, column)` offset, `None` if this leaves a `width` by `height` area.
+    #[must_use]
+    pub fn step_within(
+        self,
+        (d_row, d_col): (isize, isize),
+        width: usize,
+        height: usize,
+    ) -> Option<Self> {
+        let x = self.x.checked_add_signed(d_col)?;
+        let y = self.y.checked_add_signed(d_row)?;
+        (x < width && y < height).then_some(Self { x, y })
+    }
+}
+
+impl From<Pos> for Point<usize> {
+    fn from((row, col): Pos) -> Self {
+        Self { x: col, y: row }
+    }
+}
+
+impl From<Point<usize>> for Pos {
+    fn from(point: Point<usize>) -> Self {
+        (point.y, point.x)
+    }
+}
+
+/// A point in space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the Manhattan distance to another point, also for unsigned coordinates.
+    pub fn manhattan(self, other: Self) -> T
+    where
+        T: PartialOrd + Sub<Output = T> + Add<Output = T>,
+    {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+}
+
+fn abs_diff<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// Implements the component-wise operators and scaling for a point type.
+macro_rules! impl_point_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                Self { $($field: self.$field * factor),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)*
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point { x, y });
+impl_point_ops!(Point3 { x, y, z });
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the four orthogonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Returns the direction after a quarter turn clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Returns the direction after a quarter turn counter-clockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction4::Up | Direction4::Down)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+
+    /// Returns the `(row, column)` offset of one step, as taken by [`Grid::step`](crate::grid::Grid::step).
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction4::Up => (-1, 0),
+            Direction4::Right => (0, 1),
+            Direction4::Down => (1, 0),
+            Direction4::Left => (0, -1),
+        }
+    }
+
+    /// Returns the vector of one step.
+    pub fn unit<T: Signed>(self) -> Point<T> {
+        unit(self.offset())
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = DirectionFromStrError;
+
+    /// Parses `U`, `R`, `D` and `L` or the arrows `^`, `>`, `v` and `<`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "^" => Ok(Direction4::Up),
+            "R" | ">" => Ok(Direction4::Right),
+            "D" | "v" => Ok(Direction4::Down),
+            "L" | "<" => Ok(Direction4::Left),
+            _ => Err(DirectionFromStrError),
+        }
+    }
+}
+
+/// One of the eight directions including diagonals, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Returns the direction after an eighth turn clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Returns the direction after an eighth turn counter-clockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// Returns the `(row, column)` offset of one step, as taken by [`Grid::step`](crate::grid::Grid::step).
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction8::Up => (-1, 0),
+            Direction8::UpRight => (-1, 1),
+            Direction8::Right => (0, 1),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (1, 0),
+            Direction8::DownLeft => (1, -1),
+            Direction8::Left => (0, -1),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+
+    /// Returns the vector of one step.
+    pub fn unit<T: Signed>(self) -> Point<T> {
+        unit(self.offset())
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+fn unit<T: Signed>((d_row, d_col): (isize, isize)) -> Point<T> {
+    let sign = |d: isize| match d {
+        1 => T::one(),
+        -1 => -T::one(),
+        _ => T::zero(),
+    };
+    Point::new(sign(d_col), sign(d_row))
+}
+
+/// An error which can be returned when parsing a [`Direction4`].
+#[derive(Debug)]
+pub struct DirectionFromStrError;
+
+impl Error for DirectionFromStrError {}
+
+impl Display for DirectionFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting U, R, D, L or one of ^, >, v, <")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction4, Direction8, Point, Point3};
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.opposite(), Direction4::Right);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.opposite(), Direction8::UpLeft);
+
+        for direction in Direction4::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(Direction8::from(direction).offset(), direction.offset());
+        }
+        assert_eq!("v".parse::<Direction4>().ok(), Some(Direction4::Down));
+        assert!("X".parse::<Direction4>().is_err());
+    }
+
+    #[test]
+    fn moves_up_towards_lower_y() {
+        let point = Point::new(2, 3) + Direction4::Up.unit() * 5;
+        assert_eq!(point, Point::new(2, -2));
+        assert_eq!(point.manhattan(Point::new(0, 0)), 4);
+    }
+
+    #[test]
+    fn measures_points() {
+        let point: Point<i64> = Point::new(1, 1) + Direction8::DownLeft.unit() * 3;
+        assert_eq!(point, Point::new(-2, 4));
+        assert_eq!(point.manhattan(Point::default()), 6);
+        assert_eq!(Point::new(1_usize, 5).manhattan(Point::new(4, 2)), 6);
+        assert_eq!(
+            Point3::new(1, 2, 3) - Point3::new(3, 2, 1),
+            Point3::new(-2, 0, 2)
+        );
+        assert_eq!(Point3::new(1_u8, 2, 3).manhattan(Point3::new(3, 2, 1)), 4);
+    }
+
+    #[test]
+    fn steps_within_bounds() {
+        let point = Point::new(0_usize, 1);
+        assert_eq!(point.step_within(Direction4::Left.offset(), 2, 2), None);
+        assert_eq!(point.step_within(Direction4::Down.offset(), 2, 2), None);
+        assert_eq!(
+            point.step_within(Direction8::UpRight.offset(), 2, 2),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(<(usize, usize)>::from(point), (1, 0));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -1,4 +1,16 @@
+mod answer;
 mod day;
+mod day_set;
+pub mod geom;
+pub mod grid;
+pub mod ocr;
+mod puzzle;
+pub mod search;
 pub mod template;
+mod year;
 
+pub use answer::*;
 pub use day::*;
+pub use day_set::*;
+pub use puzzle::*;
+pub use year::*;
@@ -1,55 +1,190 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, bench_compare, download, read, scaffold, solve, verify,
+};
 use args::{parse, AppArguments};
 
 mod args {
+    use std::env;
+    use std::ffi::OsString;
+    use std::num::NonZeroUsize;
     use std::process;
+    use std::time::Duration;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::commands::all::Selection;
+    use advent_of_code::template::commands::bench_compare::{parse_threshold, DEFAULT_THRESHOLD};
+    use advent_of_code::template::commands::solve::Watch;
+    use advent_of_code::template::input::Input;
+    use advent_of_code::template::report::Format;
+    use advent_of_code::template::runner::parse_duration;
+    use advent_of_code::{Day, PuzzleId, Year};
+    use pico_args::Arguments;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
+            force: bool,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
+            template: Option<String>,
+            answer_type: Option<String>,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
+            input: Input,
             release: bool,
             time: bool,
+            bench_time: Option<Duration>,
+            mem: bool,
+            timeout: Option<Duration>,
             submit: Option<u8>,
+            watch: Option<Watch>,
         },
         All {
+            year: Year,
             release: bool,
             time: bool,
+            bench_time: Option<Duration>,
+            mem: bool,
+            timeout: Option<Duration>,
+            format: Format,
+            jobs: NonZeroUsize,
+            selection: Selection,
+        },
+        BenchCompare {
+            year: Year,
+            baseline: Option<String>,
+            threshold: f64,
+            bench_time: Option<Duration>,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
+            release: bool,
         },
     }
 
+    /// Reads the year from `--year`, falling back to `AOC_YEAR`.
+    fn parse_year(args: &mut Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "no year given, pass `--year` or set `AOC_YEAR`.".into()),
+        }
+    }
+
+    /// Reads the day and the year of a puzzle.
+    fn parse_puzzle(args: &mut Arguments) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        Ok(PuzzleId::new(year, args.free_from_str()?))
+    }
+
+    /// Reads the input of `solve` from `--input <path>`, `--input -` or `--example [N]`.
+    fn parse_input(args: &mut Arguments) -> Result<Input, Box<dyn std::error::Error>> {
+        let input: Option<Input> = args.opt_value_from_str("--input")?;
+        let example: Option<u8> = args.opt_value_from_str("--example")?;
+
+        match (input, example) {
+            (Some(_), Some(_)) => Err("`--input` can not be combined with `--example`.".into()),
+            (_, Some(0)) => Err("expecting an example number like `--example 2`.".into()),
+            (input, example) => Ok(example.map_or(input.unwrap_or_default(), Input::Example)),
+        }
+    }
+
+    /// `--example` takes an optional number, which `pico_args` does not support: default it to the first example.
+    fn with_example_number(mut args: Vec<OsString>) -> Vec<OsString> {
+        if let Some(index) = args.iter().position(|x| x == "--example") {
+            let has_number = args
+                .get(index + 1)
+                .and_then(|x| x.to_str())
+                .is_some_and(|x| x.parse::<u8>().is_ok());
+            if !has_number {
+                args.insert(index + 1, "1".into());
+            }
+        }
+        args
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+        let mut args = Arguments::from_vec(with_example_number(env::args_os().skip(1).collect()));
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
                 time: args.contains("--time"),
+                bench_time: args.opt_value_from_fn("--bench-time", parse_duration)?,
+                mem: args.contains("--mem"),
+                timeout: args.opt_value_from_fn("--timeout", parse_duration)?,
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                jobs: args
+                    .opt_value_from_str("--jobs")?
+                    .unwrap_or(NonZeroUsize::MIN),
+                selection: Selection {
+                    days: args.opt_value_from_str("--days")?,
+                    exclude: args.opt_value_from_str("--exclude")?,
+                    only_unsolved: args.contains("--only-unsolved"),
+                    slowest: args.opt_value_from_str("--slowest")?,
+                },
+            },
+            Some("bench-compare") => AppArguments::BenchCompare {
+                year: parse_year(&mut args)?,
+                threshold: args
+                    .opt_value_from_fn("--threshold", parse_threshold)?
+                    .unwrap_or(DEFAULT_THRESHOLD),
+                bench_time: args.opt_value_from_fn("--bench-time", parse_duration)?,
+                baseline: args.opt_free_from_str()?,
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
+                force: args.contains("--force"),
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                template: args.opt_value_from_str("--template")?,
+                answer_type: args.opt_value_from_str("--answer-type")?,
+                puzzle: parse_puzzle(&mut args)?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+            Some("solve") => {
+                let submit = args.opt_value_from_str("--submit")?;
+                let watch = args.contains("--watch").then(|| Watch {
+                    tests: !args.contains("--no-tests"),
+                });
+
+                if submit.is_some() && watch.is_some() {
+                    return Err("`--watch` can not be combined with `--submit`.".into());
+                }
+
+                let input = parse_input(&mut args)?;
+
+                if submit.is_some() && input != Input::Puzzle {
+                    return Err("`--submit` can only be used with the puzzle input.".into());
+                }
+                if watch.is_some() && input == Input::Stdin {
+                    return Err("`--watch` can not read the input from stdin.".into());
+                }
+
+                AppArguments::Solve {
+                    puzzle: parse_puzzle(&mut args)?,
+                    input,
+                    release: args.contains("--release"),
+                    submit,
+                    time: args.contains("--time"),
+                    bench_time: args.opt_value_from_fn("--bench-time", parse_duration)?,
+                    mem: args.contains("--mem"),
+                    timeout: args.opt_value_from_fn("--timeout", parse_duration)?,
+                    watch,
+                }
+            }
+            Some("verify") => AppArguments::Verify {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                day: args.opt_free_from_str()?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -77,16 +212,46 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                time,
+                bench_time,
+                mem,
+                timeout,
+                format,
+                jobs,
+                selection,
+            } => all::handle(
+                year, release, time, bench_time, mem, timeout, format, jobs, &selection,
+            ),
+            AppArguments::BenchCompare {
+                year,
+                baseline,
+                threshold,
+                bench_time,
+            } => bench_compare::handle(year, baseline.as_deref(), threshold, bench_time),
+            AppArguments::Download { puzzle, force } => download::handle(puzzle, force),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold {
+                puzzle,
+                template,
+                answer_type,
+            } => scaffold::handle(puzzle, template.as_deref(), answer_type.as_deref()),
             AppArguments::Solve {
-                day,
+                puzzle,
+                input,
                 release,
                 time,
+                bench_time,
+                mem,
+                timeout,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                watch,
+            } => solve::handle(
+                puzzle, &input, release, time, bench_time, mem, timeout, submit, watch,
+            ),
+            AppArguments::Verify { year, day, release } => verify::handle(year, day, release),
         },
     };
 }
@@ -0,0 +1,204 @@
+//! Module that reads letters drawn in pixels, as in the answers of some puzzles.
+//! Both fonts of advent are supported: letters that are 6 pixels high (mostly 4 wide) and letters that are 10 pixels high (6 wide).
+//! Letters are separated by empty columns, lit pixels are drawn as `#` or `█`.
+
+/// Letters of the font that is 6 pixels high.
+const FONT_6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters of the font that is 10 pixels high.
+const FONT_10: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// Reads the letters drawn in a string, e.g. the output of a part. Returns `None` if any letter is not recognised.
+#[must_use]
+pub fn read(art: &str) -> Option<String> {
+    let pixels: Vec<Vec<bool>> = art
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+    read_pixels(&pixels)
+}
+
+/// Reads the letters drawn in rows of pixels, `true` for a lit pixel.
+/// Returns `None` if the height does not match a font or any letter is not recognised.
+#[must_use]
+pub fn read_pixels(pixels: &[Vec<bool>]) -> Option<String> {
+    let is_empty = |row: &Vec<bool>| !row.contains(&true);
+    let first = pixels.iter().position(|row| !is_empty(row))?;
+    let last = pixels.iter().rposition(|row| !is_empty(row))?;
+    let rows = &pixels[first..=last];
+
+    let font: &[(char, &str)] = match rows.len() {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let is_lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let is_empty_column = |x: usize| (0..rows.len()).all(|y| !is_lit(x, y));
+
+    let mut letters = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if is_empty_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && !is_empty_column(x) {
+            x += 1;
+        }
+
+        let glyph: Vec<String> = (0..rows.len())
+            .map(|y| {
+                (start..x)
+                    .map(|x| if is_lit(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let glyph = glyph.join("\n");
+
+        let (letter, _) = font.iter().find(|(_, art)| trim_columns(art) == glyph)?;
+        letters.push(*letter);
+    }
+
+    (!letters.is_empty()).then_some(letters)
+}
+
+/// Removes the empty columns at both sides of a letter of a font, e.g. the leading column of `I`.
+fn trim_columns(art: &str) -> String {
+    let rows: Vec<&str> = art.lines().collect();
+    let is_empty_column = |x: usize| rows.iter().all(|row| row.as_bytes()[x] == b'.');
+
+    let width = rows[0].len();
+    let start = (0..width).find(|x| !is_empty_column(*x)).unwrap_or(0);
+    let end = (0..width).rfind(|x| !is_empty_column(*x)).unwrap_or(0);
+
+    rows.iter()
+        .map(|row| &row[start..=end])
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{read, read_pixels, FONT_10, FONT_6};
+
+    #[test]
+    fn reads_small_letters() {
+        let art = [
+            "#..#.####.###..####.",
+            "#..#.#....#..#....#.",
+            "####.###..#..#...#..",
+            "#..#.#....###...#...",
+            "#..#.#....#.#..#....",
+            "#..#.####.#..#.####.",
+        ]
+        .join("\n");
+        assert_eq!(read(&art).as_deref(), Some("HERZ"));
+
+        let pixels: Vec<Vec<bool>> = art
+            .lines()
+            .map(|line| line.chars().map(|c| c == '#').collect())
+            .collect();
+        assert_eq!(read_pixels(&pixels).as_deref(), Some("HERZ"));
+    }
+
+    #[test]
+    fn reads_every_letter() {
+        for (letter, art) in FONT_6.iter().chain(&FONT_10) {
+            let padded = format!("\n{}\n", art.replace('.', " "));
+            assert_eq!(read(&padded), Some(letter.to_string()));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_art() {
+        assert_eq!(read(""), None);
+        assert_eq!(read("#\n#\n#"), None);
+        assert_eq!(read("#\n#\n#\n#\n#\n#"), None);
+        assert_eq!(read("12"), None);
+    }
+}
@@ -0,0 +1,437 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use num::Zero;
+
+/// A shortest path found by a search, from the start to the goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+impl<N, C> Path<N, C> {
+    /// Returns the node that satisfied the goal.
+    pub fn goal(&self) -> &N {
+        self.nodes
+            .last()
+            .expect("a path contains at least the start")
+    }
+}
+
+/// Finds a cheapest path from `start` to a node that satisfies `is_goal`.
+/// `neighbors` returns the nodes reachable from a node, with the cost of moving there.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbors, |_| C::zero(), is_goal)
+}
+
+/// Like [`dijkstra`], but explores the nodes in order of their cost plus the `heuristic` estimate of the
+/// remaining cost. The heuristic must never overestimate, e.g. the Manhattan distance to the goal.
+/// Visited nodes are visited again if a cheaper way to them is found, which only happens with heuristics
+/// whose estimate drops by more than the cost of a move.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(start, neighbors, heuristic, is_goal, BinaryHeap::new())
+}
+
+/// Finds a path with the fewest steps from `start` to a node that satisfies `is_goal`.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    search(
+        start,
+        |node| neighbors(node).into_iter().map(|next| (next, 1)),
+        |_| 0,
+        is_goal,
+        VecDeque::new(),
+    )
+}
+
+/// Like [`dijkstra`] for moves that are either free or cost 1, in linear time.
+///
+/// # Panics
+/// Panics if a move costs more than 1.
+pub fn zero_one_bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    search(
+        start,
+        |node| {
+            neighbors(node).into_iter().inspect(|(_, cost)| {
+                assert!(*cost <= 1, "0-1 BFS got a move that costs {cost}");
+            })
+        },
+        |_| 0,
+        is_goal,
+        VecDeque::new(),
+    )
+}
+
+/// Like [`dijkstra`], but keeps the nodes to visit in a [`BucketQueue`].
+/// This is faster when the costs of moves are small integers, e.g. the digits of a grid.
+pub fn dijkstra_buckets<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    search(start, neighbors, |_| 0, is_goal, BucketQueue::new())
+}
+
+/// Explores the nodes in the order given by `frontier`, every search above is built on it.
+/// Nodes are stored once in `visited` and referred to by index, with the index of the node they were reached from.
+fn search<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut frontier: impl Frontier<C>,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited: Vec<Visited<N, C>> = Vec::new();
+    let mut indices: HashMap<N, usize> = HashMap::new();
+
+    frontier.push(heuristic(&start), C::zero(), 0);
+    indices.insert(start.clone(), 0);
+    visited.push(Visited {
+        node: start,
+        parent: None,
+        cost: C::zero(),
+        is_done: false,
+    });
+
+    while let Some(index) = frontier.pop() {
+        // a node is queued again whenever a cheaper way to it is found, only the visit with its lowest cost counts.
+        if visited[index].is_done {
+            continue;
+        }
+        visited[index].is_done = true;
+
+        let cost = visited[index].cost;
+        if is_goal(&visited[index].node) {
+            return Some(Path {
+                nodes: reconstruct_path(&visited, index),
+                cost,
+            });
+        }
+
+        for (next, step) in neighbors(&visited[index].node) {
+            let next_cost = cost + step;
+            let next_index = match indices.entry(next) {
+                Entry::Vacant(entry) => {
+                    let next_index = visited.len();
+                    visited.push(Visited {
+                        node: entry.key().clone(),
+                        parent: Some(index),
+                        cost: next_cost,
+                        is_done: false,
+                    });
+                    entry.insert(next_index);
+                    next_index
+                }
+                Entry::Occupied(entry) => {
+                    let next_visited = &mut visited[*entry.get()];
+                    if next_visited.cost <= next_cost {
+                        continue;
+                    }
+                    next_visited.parent = Some(index);
+                    next_visited.cost = next_cost;
+                    next_visited.is_done = false;
+                    *entry.get()
+                }
+            };
+
+            let estimate = next_cost + heuristic(&visited[next_index].node);
+            frontier.push(estimate, step, next_index);
+        }
+    }
+
+    None
+}
+
+struct Visited<N, C> {
+    node: N,
+    parent: Option<usize>,
+    cost: C,
+    is_done: bool,
+}
+
+/// Walks back from the node at `index` to the start.
+fn reconstruct_path<N: Clone, C>(visited: &[Visited<N, C>], index: usize) -> Vec<N> {
+    let mut nodes = vec![visited[index].node.clone()];
+    let mut current = index;
+    while let Some(parent) = visited[current].parent {
+        nodes.push(visited[parent].node.clone());
+        current = parent;
+    }
+    nodes.reverse();
+    nodes
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The nodes that are left to visit, by index.
+trait Frontier<C> {
+    /// Queues a node with the estimated cost of a path through it, `step` is the cost of the move that reached it.
+    fn push(&mut self, estimate: C, step: C, index: usize);
+
+    /// Takes the node with the lowest estimate.
+    fn pop(&mut self) -> Option<usize>;
+}
+
+impl<C: Ord> Frontier<C> for BinaryHeap<(Reverse<C>, usize)> {
+    fn push(&mut self, estimate: C, _step: C, index: usize) {
+        self.push((Reverse(estimate), index));
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        self.pop().map(|(_, index)| index)
+    }
+}
+
+/// Without a heuristic and with moves of cost 0 or 1, free moves go first and the others last.
+impl Frontier<usize> for VecDeque<usize> {
+    fn push(&mut self, _estimate: usize, step: usize, index: usize) {
+        if step == 0 {
+            self.push_front(index);
+        } else {
+            self.push_back(index);
+        }
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        self.pop_front()
+    }
+}
+
+impl Frontier<usize> for BucketQueue<usize> {
+    fn push(&mut self, estimate: usize, _step: usize, index: usize) {
+        self.push(estimate, index);
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        self.pop().map(|(_, index)| index)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A monotone priority queue for small integer priorities, with one bucket per priority.
+/// Items pop in order of priority, which must never be lower than the priority of the last popped item.
+#[derive(Debug, Clone)]
+pub struct BucketQueue<T> {
+    buckets: Vec<Vec<T>>,
+    current: usize,
+    len: usize,
+}
+
+impl<T> BucketQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            buckets: Vec::new(),
+            current: 0,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// # Panics
+    /// Panics if `priority` is lower than the priority of the last popped item.
+    pub fn push(&mut self, priority: usize, item: T) {
+        assert!(
+            priority >= self.current,
+            "priority {priority} is lower than the last popped priority {}",
+            self.current
+        );
+        if priority >= self.buckets.len() {
+            self.buckets.resize_with(priority + 1, Vec::new);
+        }
+        self.buckets[priority].push(item);
+        self.len += 1;
+    }
+
+    /// Takes an item with the lowest priority.
+    pub fn pop(&mut self) -> Option<(usize, T)> {
+        if self.is_empty() {
+            return None;
+        }
+        while self.buckets[self.current].is_empty() {
+            self.current += 1;
+        }
+        self.len -= 1;
+        let item = self.buckets[self.current].pop()?;
+        Some((self.current, item))
+    }
+}
+
+impl<T> Default for BucketQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dijkstra, dijkstra_buckets, zero_one_bfs, BucketQueue};
+    use crate::grid::{Grid, Pos};
+
+    fn maze() -> Grid<char> {
+        ["S.#.", ".##.", "...E"].join("\n").parse().unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>, pos: Pos) -> Vec<Pos> {
+        grid.neighbours4(pos)
+            .filter(|&next| grid[next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let grid = maze();
+        let goal = grid.find(&'E').unwrap();
+
+        let path = bfs(
+            (0, 0),
+            |&pos| open_neighbours(&grid, pos),
+            |&pos| pos == goal,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.nodes, [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)]);
+        assert_eq!(*path.goal(), goal);
+
+        let unit_costs = |&pos: &Pos| {
+            open_neighbours(&grid, pos)
+                .into_iter()
+                .map(|next| (next, 1_u32))
+        };
+        let path = dijkstra((0, 0), unit_costs, |&pos| pos == goal).unwrap();
+        assert_eq!(path.cost, 5);
+
+        let manhattan = |&(row, col): &Pos| (goal.0.abs_diff(row) + goal.1.abs_diff(col)) as u32;
+        let path = astar((0, 0), unit_costs, manhattan, |&pos| pos == goal).unwrap();
+        assert_eq!(path.cost, 5);
+
+        let is_wall = |&pos: &Pos| grid[pos] == '#';
+        assert_eq!(
+            bfs((0, 0), |&pos| open_neighbours(&grid, pos), is_wall),
+            None
+        );
+    }
+
+    #[test]
+    fn prefers_cheaper_moves() {
+        // steps of 1 cost 2, steps of 2 cost 3.
+        let path = dijkstra(0, |&n| [(n + 1, 2), (n + 2, 3)], |&n| n == 4).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.nodes, [0, 2, 4]);
+    }
+
+    #[test]
+    fn revisits_nodes_with_inconsistent_heuristics() {
+        // the estimate of `a` is exact, but drops by more than the cost of the move to `b`.
+        let moves = |node: &char| match node {
+            's' => vec![('a', 1), ('b', 3)],
+            'a' => vec![('b', 1)],
+            'b' => vec![('g', 3)],
+            _ => vec![],
+        };
+        let heuristic = |node: &char| if *node == 'a' { 4 } else { 0 };
+
+        let path = astar('s', moves, heuristic, |node| *node == 'g').unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.nodes, ['s', 'a', 'b', 'g']);
+    }
+
+    #[test]
+    fn weighs_moves() {
+        // moving right costs 0 and moving down costs 1, within 3 rows and 6 columns.
+        let moves = |&(row, col): &Pos| {
+            [((row, col + 1), 0), ((row + 1, col), 1)]
+                .into_iter()
+                .filter(|((row, col), _)| *row < 3 && *col < 6)
+        };
+        let is_goal = |&pos: &Pos| pos == (2, 5);
+
+        assert_eq!(zero_one_bfs((0, 0), moves, is_goal).unwrap().cost, 2);
+        assert_eq!(dijkstra_buckets((0, 0), moves, is_goal).unwrap().cost, 2);
+        assert_eq!(dijkstra((0, 0), moves, is_goal).unwrap().nodes.len(), 8);
+    }
+
+    #[test]
+    fn pops_lower_priorities_pushed_later() {
+        let mut queue = BucketQueue::new();
+        queue.push(3, 'b');
+        queue.push(1, 'a');
+        assert_eq!(queue.pop(), Some((1, 'a')));
+        queue.push(2, 'c');
+        assert_eq!(queue.pop(), Some((2, 'c')));
+    }
+
+    #[test]
+    fn pops_buckets_in_order() {
+        let mut queue = BucketQueue::new();
+        for (priority, item) in [(4, 'd'), (0, 'a'), (2, 'c'), (2, 'b')] {
+            queue.push(priority, item);
+        }
+        assert_eq!(queue.len(), 4);
+        assert_eq!(queue.pop(), Some((0, 'a')));
+        assert_eq!(queue.pop().map(|(priority, _)| priority), Some(2));
+        assert_eq!(queue.pop().map(|(priority, _)| priority), Some(2));
+        queue.push(3, 'e');
+        assert_eq!(queue.pop(), Some((3, 'e')));
+        assert_eq!(queue.pop(), Some((4, 'd')));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
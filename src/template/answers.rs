@@ -0,0 +1,198 @@
+/// Module that stores accepted answers in `data/<year>/answers/<day>.toml`.
+/// Only the subset of TOML needed for `part_one = "..."` style entries is supported.
+use std::{fs, io};
+
+use crate::template::data_dir;
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// Accepted answers for both parts of a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.into()),
+            2 => self.part_two = Some(answer.into()),
+            _ => {}
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.part_one.is_none() && self.part_two.is_none()
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: PuzzleId) -> String {
+    format!("{}/{}.toml", data_dir(puzzle.year, "answers"), puzzle.day)
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn unescape(s: &str) -> Result<String, Error> {
+    let Some(inner) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) else {
+        // bare integers are accepted for hand-written files.
+        return match s.parse::<i128>() {
+            Ok(n) => Ok(n.to_string()),
+            Err(_) => Err(Error::Parser(format!("invalid value `{s}`"))),
+        };
+    };
+
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('"') => result.push('"'),
+            Some('\\') => result.push('\\'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            _ => return Err(Error::Parser(format!("invalid escape in `{s}`"))),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Parses the contents of an answers file.
+pub fn parse(s: &str) -> Result<Answers, Error> {
+    let mut answers = Answers::default();
+
+    for line in s.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| Error::Parser(format!("expected `key = value`, found `{line}`")))?;
+
+        let value = unescape(value.trim())?;
+
+        match key.trim() {
+            "part_one" => answers.part_one = Some(value),
+            "part_two" => answers.part_two = Some(value),
+            key => return Err(Error::Parser(format!("unknown key `{key}`"))),
+        }
+    }
+
+    Ok(answers)
+}
+
+/// Serializes answers to the contents of an answers file.
+#[must_use]
+pub fn serialize(answers: &Answers) -> String {
+    let mut lines = vec![];
+
+    if let Some(answer) = &answers.part_one {
+        lines.push(format!("part_one = {}", escape(answer)));
+    }
+
+    if let Some(answer) = &answers.part_two {
+        lines.push(format!("part_two = {}", escape(answer)));
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Reads the recorded answers of a day. A missing file yields no answers.
+pub fn read(puzzle: PuzzleId) -> Result<Answers, Error> {
+    match fs::read_to_string(get_path(puzzle)) {
+        Ok(s) => parse(&s),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Records an accepted answer for one part of a day.
+pub fn record(puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), Error> {
+    let mut answers = read(puzzle)?;
+    answers.set(part, answer);
+    fs::create_dir_all(data_dir(puzzle.year, "answers"))?;
+    fs::write(get_path(puzzle), serialize(&answers))?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, serialize, Answers};
+
+    #[test]
+    fn round_trips_answers() {
+        let answers = Answers {
+            part_one: Some("42".into()),
+            part_two: Some("#..#\n\"quoted\" \\ path".into()),
+        };
+        assert_eq!(parse(&serialize(&answers)).unwrap(), answers);
+    }
+
+    #[test]
+    fn parses_hand_written_files() {
+        let answers = parse("# day 1\npart_two = 281\n\n part_one=\"142\"  \n").unwrap();
+        assert_eq!(answers.get(1), Some("142"));
+        assert_eq!(answers.get(2), Some("281"));
+    }
+
+    #[test]
+    fn serializes_missing_parts() {
+        let answers = Answers {
+            part_one: None,
+            part_two: Some("7".into()),
+        };
+        assert_eq!(serialize(&answers), "part_two = \"7\"\n");
+    }
+
+    #[test]
+    fn rejects_invalid_files() {
+        assert!(parse("part_three = \"1\"").is_err());
+        assert!(parse("part_one \"1\"").is_err());
+        assert!(parse("part_one = abc").is_err());
+    }
+}
@@ -0,0 +1,581 @@
+/// Native client for adventofcode.com.
+/// Requests go through a [`Transport`], which lets tests point the client at a local mock server.
+use std::fmt::Display;
+use std::time::Duration;
+use std::{env, fs, io};
+
+use crate::template::html;
+use crate::PuzzleId;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable holding the session cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable holding the path of a file containing the session cookie.
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+
+// https://www.reddit.com/r/adventofcode/wiki/faqs/automation
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum Error {
+    /// No session cookie was configured.
+    MissingSession,
+    /// The session cookie was rejected, it has probably expired.
+    Auth,
+    /// An answer was submitted too recently, holds the time left to wait if known.
+    RateLimited(Option<Duration>),
+    /// The puzzle has not been unlocked yet.
+    TooEarly,
+    /// The submitted answer is wrong, holds the time to wait before the next submission if known.
+    WrongAnswer(Option<Hint>, Option<Duration>),
+    /// The submitted part has already been solved or is not unlocked yet.
+    WrongLevel,
+    /// The server responded with an unexpected status code.
+    Http(u16),
+    /// The request could not be sent.
+    Transport(String),
+    /// The response could not be understood.
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session cookie found. Set {SESSION_ENV} or create ~/.adventofcode.session."
+            ),
+            Error::Auth => write!(
+                f,
+                "the session cookie was rejected, it has probably expired."
+            ),
+            Error::RateLimited(Some(wait)) => write!(
+                f,
+                "an answer was submitted too recently, wait {}s before submitting again.",
+                wait.as_secs()
+            ),
+            Error::RateLimited(None) => write!(f, "an answer was submitted too recently."),
+            Error::TooEarly => write!(f, "the puzzle has not been unlocked yet."),
+            Error::WrongAnswer(Some(hint), _) => {
+                write!(f, "that's not the right answer, {hint}.")
+            }
+            Error::WrongAnswer(None, _) => write!(f, "that's not the right answer."),
+            Error::WrongLevel => write!(
+                f,
+                "this part has already been solved or is not unlocked yet."
+            ),
+            Error::Http(status) => write!(f, "unexpected response status {status}."),
+            Error::Transport(e) => write!(f, "request failed: {e}"),
+            Error::Parser(e) => write!(f, "unexpected response: {e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Hint given by the server for a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::TooHigh => f.write_str("your answer is too high"),
+            Hint::TooLow => f.write_str("your answer is too low"),
+        }
+    }
+}
+
+/// Status code and body of a response.
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends requests to the server. Responses with error status codes are returned, not treated as errors.
+pub trait Transport {
+    fn get(&self, url: &str, session: &str) -> Result<Response, Error>;
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, Error>;
+}
+
+/// [`Transport`] that sends requests over HTTP(S).
+pub struct HttpTransport {
+    agent: ureq::Agent,
+}
+
+impl Default for HttpTransport {
+    fn default() -> Self {
+        Self {
+            // redirects are not followed, the server redirects to the login page if the session is invalid.
+            agent: ureq::AgentBuilder::new()
+                .redirects(0)
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+}
+
+impl HttpTransport {
+    fn into_response(result: Result<ureq::Response, ureq::Error>) -> Result<Response, Error> {
+        let response = match result {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(Error::Transport(e.to_string())),
+        };
+
+        Ok(Response {
+            status: response.status(),
+            body: response.into_string()?,
+        })
+    }
+}
+
+impl Transport for HttpTransport {
+    fn get(&self, url: &str, session: &str) -> Result<Response, Error> {
+        Self::into_response(
+            self.agent
+                .get(url)
+                .set("Cookie", &format!("session={session}"))
+                .call(),
+        )
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, Error> {
+        Self::into_response(
+            self.agent
+                .post(url)
+                .set("Cookie", &format!("session={session}"))
+                .send_form(form),
+        )
+    }
+}
+
+pub struct AocClient {
+    transport: Box<dyn Transport>,
+    base_url: String,
+    session: String,
+}
+
+/// Reads the session cookie from `AOC_SESSION`, the file named by `AOC_SESSION_FILE` or `~/.adventofcode.session`.
+pub fn get_session() -> Result<String, Error> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let path = env::var(SESSION_FILE_ENV).ok().or_else(|| {
+        env::var("HOME")
+            .or_else(|_| env::var("USERPROFILE"))
+            .ok()
+            .map(|home| format!("{home}/.adventofcode.session"))
+    });
+
+    match path.map(fs::read_to_string) {
+        Some(Ok(session)) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Some(Err(e)) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+        _ => Err(Error::MissingSession),
+    }
+}
+
+/// Parses the time left to wait from a message such as `You have 1m 5s left to wait.`
+fn parse_wait(message: &str) -> Option<Duration> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+
+    message[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let split = part.find(|c: char| !c.is_ascii_digit())?;
+            let value: u64 = part[..split].parse().ok()?;
+            match &part[split..] {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Parses the time to wait after a wrong answer from a message such as `please wait 5 minutes before trying again.`
+/// The wait grows with every wrong answer, the first ones are spelled out, e.g. `Please wait one minute`.
+fn parse_retry_wait(message: &str) -> Option<Duration> {
+    let start = message.to_ascii_lowercase().find("please wait ")? + "please wait ".len();
+    let mut words = message[start..].split_whitespace();
+
+    let value = match words.next()? {
+        "one" | "a" | "an" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        value => value.parse().ok()?,
+    };
+
+    let unit = words.next()?.trim_end_matches(|c: char| !c.is_alphabetic());
+    let seconds = match unit.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 3600,
+        _ => return None,
+    };
+
+    Some(Duration::from_secs(value * seconds))
+}
+
+/// Interprets the message returned for a submitted answer.
+fn parse_submit_response(message: &str) -> Result<String, Error> {
+    if message.contains("That's the right answer") {
+        Ok(message.to_string())
+    } else if message.contains("That's not the right answer") {
+        let hint = if message.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if message.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Err(Error::WrongAnswer(hint, parse_retry_wait(message)))
+    } else if message.contains("You gave an answer too recently") {
+        Err(Error::RateLimited(parse_wait(message)))
+    } else if message.contains("You don't seem to be solving the right level") {
+        Err(Error::WrongLevel)
+    } else if message.contains("before it unlocks") {
+        Err(Error::TooEarly)
+    } else {
+        Err(Error::Parser(message.to_string()))
+    }
+}
+
+impl AocClient {
+    pub fn new(transport: Box<dyn Transport>, base_url: &str, session: &str) -> Self {
+        Self {
+            transport,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// Creates a client for adventofcode.com, reading the session cookie from the environment.
+    pub fn from_env() -> Result<Self, Error> {
+        let session = get_session()?;
+        Ok(Self::new(
+            Box::<HttpTransport>::default(),
+            BASE_URL,
+            &session,
+        ))
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn check_status(response: &Response) -> Result<(), Error> {
+        match response.status {
+            200 => Ok(()),
+            404 => Err(Error::TooEarly),
+            // the server redirects to the login page or rejects the request if the session is invalid.
+            302 | 400 | 401 | 403 => Err(Error::Auth),
+            status => Err(Error::Http(status)),
+        }
+    }
+
+    /// Fetches the personal input of a puzzle.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, Error> {
+        let response = self
+            .transport
+            .get(&format!("{}/input", self.day_url(puzzle)), &self.session)?;
+        Self::check_status(&response)?;
+        Ok(response.body)
+    }
+
+    /// Fetches the description of a puzzle as markdown, including the answers to solved parts.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, Error> {
+        let response = self.transport.get(&self.day_url(puzzle), &self.session)?;
+        Self::check_status(&response)?;
+
+        let main = html::find_elements(&response.body, "main", "")
+            .first()
+            .copied()
+            .unwrap_or(&response.body);
+
+        let articles = html::find_elements(main, "article", "day-desc");
+        if articles.is_empty() {
+            return Err(Error::Parser("no puzzle description found".into()));
+        }
+
+        // answers to solved parts follow the article of each part.
+        let answers = html::find_elements(main, "p", "")
+            .into_iter()
+            .filter(|p| p.starts_with("Your puzzle answer was"));
+
+        let mut blocks: Vec<String> = articles.into_iter().map(html::to_markdown).collect();
+        for (i, answer) in answers.enumerate() {
+            if let Some(block) = blocks.get_mut(i) {
+                block.push_str("\n\n");
+                block.push_str(&html::to_markdown(answer));
+            }
+        }
+
+        Ok(blocks.join("\n\n") + "\n")
+    }
+
+    /// Submits an answer and returns the message of the server if it was accepted.
+    pub fn submit(&self, puzzle: PuzzleId, part: u8, answer: &str) -> Result<String, Error> {
+        let response = self.transport.post(
+            &format!("{}/answer", self.day_url(puzzle)),
+            &self.session,
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        Self::check_status(&response)?;
+
+        let message = html::find_elements(&response.body, "article", "")
+            .first()
+            .map(|article| html::to_markdown(article))
+            .ok_or_else(|| Error::Parser("no message found in response".into()))?;
+
+        parse_submit_response(&message)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_retry_wait, parse_wait, AocClient, Error, Hint, HttpTransport};
+    use crate::{day, year, PuzzleId};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    type Requests = Arc<Mutex<Vec<String>>>;
+
+    /// Starts a local HTTP server answering every request with the response of `route`.
+    /// Returns its base url and the request lines, cookies and bodies it received.
+    fn mock_server(route: fn(&str) -> (u16, &'static str)) -> (String, Requests) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests: Requests = Arc::default();
+        let received = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                let mut cookie = String::new();
+
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(": ").unwrap_or_default();
+                    match name.to_lowercase().as_str() {
+                        "content-length" => content_length = value.parse().unwrap(),
+                        "cookie" => cookie = value.to_string(),
+                        _ => {}
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                let request_line = request_line.trim_end().to_string();
+                let (status, response) = route(&request_line);
+                received.lock().unwrap().push(format!(
+                    "{request_line} [{cookie}] {}",
+                    String::from_utf8_lossy(&body)
+                ));
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn client(url: &str) -> AocClient {
+        AocClient::new(Box::<HttpTransport>::default(), url, "abc")
+    }
+
+    macro_rules! puzzle {
+        ($day:expr) => {
+            PuzzleId::new(year!(2023), day!($day))
+        };
+    }
+
+    #[test]
+    fn downloads_inputs() {
+        let (url, requests) = mock_server(|request| match request {
+            "GET /2023/day/1/input HTTP/1.1" => (200, "1abc2\npqr3stu8vwx\n"),
+            "GET /2022/day/1/input HTTP/1.1" => (200, "1000\n2000\n"),
+            _ => (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+        });
+
+        assert_eq!(
+            client(&url).input(puzzle!(1)).unwrap(),
+            "1abc2\npqr3stu8vwx\n"
+        );
+        assert!(matches!(
+            client(&url).input(puzzle!(2)),
+            Err(Error::TooEarly)
+        ));
+        assert_eq!(
+            client(&url)
+                .input(PuzzleId::new(year!(2022), day!(1)))
+                .unwrap(),
+            "1000\n2000\n"
+        );
+        assert_eq!(
+            requests.lock().unwrap()[0],
+            "GET /2023/day/1/input HTTP/1.1 [session=abc] "
+        );
+    }
+
+    #[test]
+    fn detects_expired_sessions() {
+        let (url, _) = mock_server(|_| (400, "Puzzle inputs differ by user.  Please log in."));
+        assert!(matches!(client(&url).input(puzzle!(1)), Err(Error::Auth)));
+    }
+
+    #[test]
+    fn downloads_puzzles_with_answers() {
+        let (url, _) = mock_server(|_| {
+            (
+                200,
+                concat!(
+                    "<html><body><main>",
+                    "<article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2><p>Part one.</p></article>",
+                    "<p>Your puzzle answer was <code>54601</code>.</p>",
+                    "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Part two.</p></article>",
+                    "<p>Your puzzle answer was <code>54078</code>.</p>",
+                    "<p>Both parts of this puzzle are complete!</p>",
+                    "</main></body></html>"
+                ),
+            )
+        });
+
+        assert_eq!(
+            client(&url).puzzle(puzzle!(1)).unwrap(),
+            [
+                "## --- Day 1: Trebuchet?! ---",
+                "",
+                "Part one.",
+                "",
+                "Your puzzle answer was `54601`.",
+                "",
+                "## --- Part Two ---",
+                "",
+                "Part two.",
+                "",
+                "Your puzzle answer was `54078`.",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, requests) = mock_server(|request| {
+            match request {
+            "POST /2023/day/1/answer HTTP/1.1" => (
+                200,
+                "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>",
+            ),
+            "POST /2023/day/2/answer HTTP/1.1" => (
+                200,
+                "<main><article><p>That's not the right answer; your answer is too high.  Please wait one minute.</p></article></main>",
+            ),
+            "POST /2023/day/3/answer HTTP/1.1" => (
+                200,
+                "<main><article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article></main>",
+            ),
+            "POST /2023/day/4/answer HTTP/1.1" => (
+                200,
+                "<main><article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article></main>",
+            ),
+            _ => (302, ""),
+        }
+        });
+
+        let client = client(&url);
+        assert!(client.submit(puzzle!(1), 1, "142").is_ok());
+        assert_eq!(
+            requests.lock().unwrap()[0],
+            "POST /2023/day/1/answer HTTP/1.1 [session=abc] level=1&answer=142"
+        );
+        assert!(matches!(
+            client.submit(puzzle!(2), 2, "9000"),
+            Err(Error::WrongAnswer(Some(Hint::TooHigh), Some(wait))) if wait == Duration::from_secs(60)
+        ));
+        assert!(matches!(
+            client.submit(puzzle!(3), 1, "1"),
+            Err(Error::RateLimited(Some(wait))) if wait == Duration::from_secs(65)
+        ));
+        assert!(matches!(
+            client.submit(puzzle!(4), 1, "1"),
+            Err(Error::WrongLevel)
+        ));
+        assert!(matches!(
+            client.submit(puzzle!(5), 1, "1"),
+            Err(Error::Auth)
+        ));
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(
+            parse_wait("You have 37s left to wait."),
+            Some(Duration::from_secs(37))
+        );
+        assert_eq!(parse_wait("Please wait."), None);
+    }
+
+    #[test]
+    fn parses_retry_wait_times() {
+        assert_eq!(
+            parse_retry_wait("Please wait one minute before trying again."),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            parse_retry_wait(
+                "You have guessed incorrectly; please wait 10 minutes before trying again."
+            ),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(parse_retry_wait("Please wait a while."), None);
+        assert_eq!(parse_retry_wait("That's not the right answer."), None);
+    }
+}
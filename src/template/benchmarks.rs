@@ -0,0 +1,492 @@
+/// Module that keeps a history of benchmark runs in `data/<year>/benchmarks/<git-sha>.json`.
+/// Runs on a working tree with uncommitted changes are saved as `<git-sha>-dirty.json` instead.
+/// Baselines are compared against fresh runs to detect performance regressions.
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::template::data_dir;
+use crate::template::json::Json;
+use crate::template::readme_benchmarks::Timings;
+use crate::template::report::PARSE_PART;
+use crate::template::stats::{format_nanos, Stats};
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    NotFound(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// Suffix of baselines recorded with uncommitted changes, so they never replace the baseline of the commit.
+pub const DIRTY_SUFFIX: &str = "-dirty";
+
+/// The timings of one benchmark run, identified by the commit it was run on.
+#[derive(Clone)]
+pub struct Baseline {
+    pub sha: String,
+    /// Unix timestamp of the run, used to find the most recent baseline.
+    pub created: u64,
+    pub timings: Vec<Timings>,
+}
+
+impl Baseline {
+    /// Returns `true` if the run had uncommitted changes.
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.sha.ends_with(DIRTY_SUFFIX)
+    }
+}
+
+#[must_use]
+pub fn get_dir(year: Year) -> String {
+    data_dir(year, "benchmarks")
+}
+
+#[must_use]
+pub fn get_path(year: Year, sha: &str) -> PathBuf {
+    Path::new(&get_dir(year)).join(format!("{sha}.json"))
+}
+
+/// Returns the short hash of the checked out commit, or `unversioned` outside of a git repository.
+#[must_use]
+pub fn current_sha() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|sha| sha.trim().to_string())
+        .filter(|sha| !sha.is_empty())
+        .unwrap_or_else(|| "unversioned".into())
+}
+
+/// Returns `true` if the working tree has uncommitted changes, `false` outside of a git repository.
+#[must_use]
+pub fn is_dirty() -> bool {
+    Command::new("git")
+        .args(["status", "--porcelain"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .is_some_and(|output| !output.stdout.trim_ascii().is_empty())
+}
+
+/// Returns the name of the baseline of the current run: the commit hash, with [`DIRTY_SUFFIX`] if `is_dirty`.
+#[must_use]
+pub fn baseline_name(sha: &str, is_dirty: bool) -> String {
+    if is_dirty {
+        format!("{sha}{DIRTY_SUFFIX}")
+    } else {
+        sha.into()
+    }
+}
+
+fn stats_to_json(stats: Option<Stats>) -> Json {
+    stats.map_or(Json::Null, |stats| {
+        Json::object()
+            .with("nanos", stats.median)
+            .with("samples", stats.samples)
+            .with("min", stats.min)
+            .with("p5", stats.p5)
+            .with("p95", stats.p95)
+            .with("stddev", stats.stddev)
+    })
+}
+
+fn parse_u128(value: &Json, field: &str) -> Result<u128, Error> {
+    value
+        .get(field)
+        .and_then(Json::as_u128)
+        .ok_or_else(|| Error::Parser(format!("missing or invalid field `{field}`")))
+}
+
+fn stats_from_json(value: Option<&Json>) -> Result<Option<Stats>, Error> {
+    match value {
+        None | Some(Json::Null) => Ok(None),
+        Some(value) => Ok(Some(Stats {
+            samples: parse_u128(value, "samples")?,
+            median: parse_u128(value, "nanos")?,
+            min: parse_u128(value, "min")?,
+            p5: parse_u128(value, "p5")?,
+            p95: parse_u128(value, "p95")?,
+            stddev: parse_u128(value, "stddev")?,
+        })),
+    }
+}
+
+/// Serializes a baseline to the contents of a baseline file.
+#[must_use]
+pub fn serialize(baseline: &Baseline) -> String {
+    let days: Vec<Json> = baseline
+        .timings
+        .iter()
+        .map(|t| {
+            Json::object()
+                .with("day", t.day.into_inner())
+                .with("parse", stats_to_json(t.parse))
+                .with("part_1", stats_to_json(t.part_1))
+                .with("part_2", stats_to_json(t.part_2))
+                .with("total_nanos", t.total_nanos)
+        })
+        .collect();
+
+    Json::object()
+        .with("sha", baseline.sha.as_str())
+        .with("created", u128::from(baseline.created))
+        .with("days", days)
+        .to_string()
+}
+
+/// Parses the contents of a baseline file.
+pub fn parse(s: &str) -> Result<Baseline, Error> {
+    let value = Json::parse(s).map_err(|e| Error::Parser(e.to_string()))?;
+
+    let sha = value
+        .get("sha")
+        .and_then(Json::as_str)
+        .ok_or_else(|| Error::Parser("missing field `sha`".into()))?;
+
+    let created = u64::try_from(parse_u128(&value, "created")?)
+        .map_err(|_| Error::Parser("invalid field `created`".into()))?;
+
+    let timings = value
+        .get("days")
+        .and_then(Json::as_array)
+        .ok_or_else(|| Error::Parser("missing field `days`".into()))?
+        .iter()
+        .map(|day_value| {
+            let day = parse_u128(day_value, "day")?;
+            let day = u8::try_from(day)
+                .ok()
+                .and_then(Day::new)
+                .ok_or_else(|| Error::Parser(format!("invalid day `{day}`")))?;
+
+            Ok(Timings {
+                day,
+                parse: stats_from_json(day_value.get("parse"))?,
+                part_1: stats_from_json(day_value.get("part_1"))?,
+                part_2: stats_from_json(day_value.get("part_2"))?,
+                memory_1: None,
+                memory_2: None,
+                total_nanos: day_value
+                    .get("total_nanos")
+                    .and_then(Json::as_f64)
+                    .unwrap_or_default(),
+            })
+        })
+        .collect::<Result<_, Error>>()?;
+
+    Ok(Baseline {
+        sha: sha.into(),
+        created,
+        timings,
+    })
+}
+
+/// Persists the timings of a run as the baseline of the current commit and returns its path.
+/// With uncommitted changes, the run is saved as a separate dirty baseline.
+pub fn save(year: Year, timings: Vec<Timings>) -> Result<PathBuf, Error> {
+    let baseline = Baseline {
+        sha: baseline_name(&current_sha(), is_dirty()),
+        created: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        timings,
+    };
+
+    let path = get_path(year, &baseline.sha);
+    fs::create_dir_all(get_dir(year))?;
+    fs::write(&path, serialize(&baseline))?;
+    Ok(path)
+}
+
+fn read(path: &Path) -> Result<Baseline, Error> {
+    parse(&fs::read_to_string(path)?)
+}
+
+/// Loads a baseline of a year, given either as a path, a (prefix of a) commit hash or `None` for the most recent one.
+/// Without a name, dirty baselines are only used if there is no baseline of a commit.
+pub fn load(year: Year, name: Option<&str>) -> Result<Baseline, Error> {
+    if let Some(name) = name {
+        let path = Path::new(name);
+        if path.is_file() {
+            return read(path);
+        }
+    }
+
+    let mut baselines = vec![];
+
+    let dir = get_dir(year);
+
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if path.extension().is_some_and(|ext| ext == "json")
+            && name.is_none_or(|name| stem.starts_with(name))
+        {
+            baselines.push(read(&path)?);
+        }
+    }
+
+    select(baselines, name).ok_or_else(|| {
+        Error::NotFound(format!(
+            "no baseline {}found in \"{dir}\"",
+            name.map(|name| format!("`{name}` ")).unwrap_or_default()
+        ))
+    })?
+}
+
+/// Picks the baseline to compare against among the baselines matching `name`.
+/// Baselines of a commit are preferred over dirty ones, unless the dirty one is named exactly.
+fn select(mut baselines: Vec<Baseline>, name: Option<&str>) -> Option<Result<Baseline, Error>> {
+    if let Some(name) = name {
+        baselines.retain(|b| b.sha.starts_with(name));
+        if let Some(index) = baselines.iter().position(|b| b.sha == name) {
+            return Some(Ok(baselines.swap_remove(index)));
+        }
+
+        let mut shas: Vec<&str> = baselines
+            .iter()
+            .map(|b| b.sha.trim_end_matches(DIRTY_SUFFIX))
+            .collect();
+        shas.sort_unstable();
+        shas.dedup();
+        if shas.len() > 1 {
+            return Some(Err(Error::NotFound(format!(
+                "baseline `{name}` is ambiguous, it matches {} commits",
+                shas.len()
+            ))));
+        }
+    }
+
+    baselines
+        .into_iter()
+        .max_by_key(|b| (!b.is_dirty(), b.created))
+        .map(Ok)
+}
+
+/// The change in median execution time of one part between a baseline and the current run.
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: Day,
+    pub part: u8,
+    pub before: Option<u128>,
+    pub after: Option<u128>,
+}
+
+impl Comparison {
+    /// Ratio of the current to the baseline time, greater than `1.0` if the part got slower.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn ratio(&self) -> Option<f64> {
+        match (self.before, self.after) {
+            (Some(before), Some(after)) if before > 0 => Some(after as f64 / before as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the part was solved in the baseline, but fails or is missing now.
+    #[must_use]
+    pub fn is_unsolved(&self) -> bool {
+        self.before.is_some() && self.after.is_none()
+    }
+
+    /// Whether the part got slower by more than `threshold`, e.g. `0.25` for 25%, or is no longer solved.
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.is_unsolved() || self.ratio().is_some_and(|ratio| ratio > 1.0 + threshold)
+    }
+
+    /// Describes the change, e.g. `1.2ms -> 2.4ms (2.00x slower)`.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let format = |nanos: Option<u128>| nanos.map_or_else(|| "-".into(), format_nanos);
+        let change = match self.ratio() {
+            Some(ratio) if ratio > 1.0 => format!(" ({ratio:.2}x slower)"),
+            Some(ratio) if ratio < 1.0 && ratio > 0.0 => format!(" ({:.2}x faster)", 1.0 / ratio),
+            Some(_) => " (unchanged)".into(),
+            None if self.is_unsolved() => " (no longer solved)".into(),
+            None => String::new(),
+        };
+        format!("{} -> {}{change}", format(self.before), format(self.after))
+    }
+}
+
+/// Compares the median times of every part present in either the baseline or the current run.
+/// The shared parse step is compared as part [`PARSE_PART`].
+#[must_use]
+pub fn compare(baseline: &[Timings], current: &[Timings]) -> Vec<Comparison> {
+    let find = |timings: &[Timings], day: Day, part: u8| {
+        timings
+            .iter()
+            .find(|t| t.day == day)
+            .and_then(|t| match part {
+                PARSE_PART => t.parse,
+                1 => t.part_1,
+                _ => t.part_2,
+            })
+            .map(|stats| stats.median)
+    };
+
+    let mut days: Vec<Day> = baseline.iter().chain(current).map(|t| t.day).collect();
+    days.sort_unstable();
+    days.dedup();
+
+    days.into_iter()
+        .flat_map(|day| (PARSE_PART..=2).map(move |part| (day, part)))
+        .map(|(day, part)| Comparison {
+            day,
+            part,
+            before: find(baseline, day, part),
+            after: find(current, day, part),
+        })
+        .filter(|c| c.before.is_some() || c.after.is_some())
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{baseline_name, compare, parse, select, serialize, Baseline, Comparison};
+    use crate::day;
+    use crate::template::readme_benchmarks::Timings;
+    use crate::template::stats::Stats;
+    use std::time::Duration;
+
+    fn stats(nanos: u64) -> Option<Stats> {
+        Some(Stats::single(Duration::from_nanos(nanos)))
+    }
+
+    fn timings() -> Vec<Timings> {
+        vec![
+            Timings {
+                day: day!(1),
+                parse: None,
+                part_1: stats(100),
+                part_2: stats(200),
+                memory_1: None,
+                memory_2: None,
+                total_nanos: 300.0,
+            },
+            Timings {
+                day: day!(2),
+                parse: None,
+                part_1: stats(1_000),
+                part_2: None,
+                memory_1: None,
+                memory_2: None,
+                total_nanos: 1_000.0,
+            },
+        ]
+    }
+
+    #[test]
+    fn round_trips_baselines() {
+        let mut timings = timings();
+        timings[1].parse = stats(50);
+
+        let baseline = Baseline {
+            sha: "2dd68f9".into(),
+            created: 1_700_000_000,
+            timings,
+        };
+        let parsed = parse(&serialize(&baseline)).unwrap();
+        assert_eq!(parsed.sha, baseline.sha);
+        assert_eq!(parsed.created, baseline.created);
+        assert_eq!(
+            compare(&parsed.timings, &baseline.timings)
+                .iter()
+                .map(Comparison::ratio)
+                .collect::<Vec<_>>(),
+            vec![Some(1.0), Some(1.0), Some(1.0), Some(1.0)]
+        );
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let mut current = timings();
+        current[0].part_1 = stats(250);
+        current[0].part_2 = stats(100);
+        current[1].part_2 = stats(10);
+
+        let comparisons = compare(&timings(), &current);
+        assert_eq!(comparisons.len(), 4);
+
+        assert!(comparisons[0].is_regression(0.25));
+        assert!(!comparisons[0].is_regression(2.0));
+        assert_eq!(
+            comparisons[0].describe(),
+            "100.0ns -> 250.0ns (2.50x slower)"
+        );
+
+        assert!(!comparisons[1].is_regression(0.0));
+        assert_eq!(
+            comparisons[1].describe(),
+            "200.0ns -> 100.0ns (2.00x faster)"
+        );
+
+        assert_eq!(comparisons[3].before, None);
+        assert!(!comparisons[3].is_regression(0.0));
+    }
+
+    #[test]
+    fn reports_parts_that_are_no_longer_solved() {
+        let mut current = timings();
+        current[0].part_2 = None;
+        current.remove(1);
+
+        let comparisons = compare(&timings(), &current);
+        assert_eq!(comparisons.len(), 3);
+        assert!(comparisons[1].is_unsolved());
+        assert!(comparisons[1].is_regression(10.0));
+        assert_eq!(comparisons[1].describe(), "200.0ns -> - (no longer solved)");
+        assert!(comparisons[2].is_regression(10.0));
+        assert!(!comparisons[0].is_unsolved());
+    }
+
+    #[test]
+    fn keeps_dirty_baselines_apart() {
+        assert_eq!(baseline_name("2dd68f9", false), "2dd68f9");
+        assert_eq!(baseline_name("2dd68f9", true), "2dd68f9-dirty");
+
+        let baseline = |sha: &str, created: u64| Baseline {
+            sha: sha.into(),
+            created,
+            timings: vec![],
+        };
+        let baselines = || {
+            vec![
+                baseline("2dd68f9", 1),
+                baseline("2dd68f9-dirty", 3),
+                baseline("72dd7cc", 2),
+            ]
+        };
+        let selected = |name| select(baselines(), name).unwrap().ok().unwrap().sha;
+
+        assert_eq!(selected(None), "72dd7cc");
+        assert_eq!(selected(Some("2dd")), "2dd68f9");
+        assert_eq!(selected(Some("2dd68f9-dirty")), "2dd68f9-dirty");
+        assert!(select(baselines(), Some("")).unwrap().is_err());
+        assert!(select(vec![baseline("2dd68f9-dirty", 3)], None)
+            .unwrap()
+            .is_ok_and(|b| b.is_dirty()));
+    }
+
+    #[test]
+    fn rejects_invalid_baselines() {
+        assert!(parse("{}").is_err());
+        assert!(parse(r#"{"sha":"abc","created":1,"days":[{"day":26}]}"#).is_err());
+    }
+}
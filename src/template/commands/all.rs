@@ -1,46 +1,334 @@
-use std::io;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::Duration;
+use std::{env, fs, io, process};
 
 use crate::template::{
+    benchmarks,
+    memory::{self, Memory},
+    protocol,
     readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    registry::{self, Solution},
+    report::{self, DayReport, Format, PartReport},
+    stats::Stats,
+    store, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{DaySet, PuzzleId, Year};
+
+use child_commands::Target;
+
+/// The days run by `cargo all`.
+/// `--only-unsolved` and `--slowest` are answered with the most recent benchmark baseline of the year.
+#[derive(Debug, Default)]
+pub struct Selection {
+    /// Days passed with `--days`, every day if not set.
+    pub days: Option<DaySet>,
+    /// Days passed with `--exclude`.
+    pub exclude: Option<DaySet>,
+    /// Only run scaffolded days that were not solved in the most recent baseline.
+    pub only_unsolved: bool,
+    /// Only run the `n` days that were slowest in the most recent baseline.
+    pub slowest: Option<usize>,
+}
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+impl Selection {
+    /// Returns `true` if every day is selected.
+    #[must_use]
+    pub fn is_all(&self) -> bool {
+        self.days.is_none_or(|days| days == DaySet::all())
+            && self.exclude.is_none_or(|days| days.is_empty())
+            && !self.only_unsolved
+            && self.slowest.is_none()
+    }
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+    /// Resolves the selection to the days to run.
+    pub fn resolve(&self, year: Year) -> Result<DaySet, benchmarks::Error> {
+        let mut days = self
+            .days
+            .unwrap_or_else(DaySet::all)
+            .difference(self.exclude.unwrap_or_default());
+
+        if !self.only_unsolved && self.slowest.is_none() {
+            return Ok(days);
         }
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+        let baseline = benchmarks::load(year, None)?;
+
+        if self.only_unsolved {
+            let solved: DaySet = baseline
+                .timings
+                .iter()
+                .filter(|t| t.part_1.is_some() && (t.part_2.is_some() || t.day == 25))
+                .map(|t| t.day)
+                .collect();
+            let scaffolded: DaySet = days
+                .iter()
+                .filter(|day| is_scaffolded(PuzzleId::new(year, *day)))
+                .collect();
+            days = scaffolded.difference(solved);
+        }
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        if let Some(n) = self.slowest {
+            let mut timings: Vec<&Timings> = baseline
+                .timings
+                .iter()
+                .filter(|t| days.contains(t.day) && t.total_nanos > 0.0)
+                .collect();
+            timings.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+            days = timings.into_iter().take(n).map(|t| t.day).collect();
+        }
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+        Ok(days)
+    }
+}
+
+/// Returns `true` if the solution module of a puzzle exists.
+pub(crate) fn is_scaffolded(puzzle: PuzzleId) -> bool {
+    Path::new(&format!("src/bin/{puzzle}.rs")).exists()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    is_release: bool,
+    is_timed: bool,
+    bench_time: Option<Duration>,
+    is_mem: bool,
+    timeout: Option<Duration>,
+    format: Format,
+    jobs: NonZeroUsize,
+    selection: &Selection,
+) {
+    let is_text = format == Format::Text;
+
+    let days = match selection.resolve(year) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Failed to select days from the benchmark baseline: {e:?}");
+            eprintln!("Run `cargo time` first to create a baseline.");
+            process::exit(1);
         }
-    });
+    };
+
+    if days.is_empty() {
+        eprintln!("No days selected.");
+        return;
+    }
+
+    // timed runs stay sequential, so that parallel days do not skew each other's timings.
+    if is_timed && jobs.get() > 1 {
+        eprintln!("Note: `--time` runs days one after another, `--jobs` is ignored.");
+    }
+
+    let reports = match if is_timed || jobs.get() == 1 {
+        child_commands::run_solutions(
+            year,
+            Target::Days(days),
+            is_timed,
+            bench_time,
+            is_mem,
+            timeout,
+            is_release,
+            is_text,
+        )
+    } else {
+        child_commands::run_solutions_parallel(
+            year, days, jobs, is_mem, timeout, is_release, is_text,
+        )
+    } {
+        Ok(reports) => reports,
+        Err(e) => {
+            eprintln!("Failed to run solutions: {e:?}");
+            process::exit(1);
+        }
+    };
+
+    let timings: Vec<Timings> = reports.iter().filter(|r| r.ran).map(get_timings).collect();
+
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", report::to_json(&reports)),
+        Format::Csv => println!("{}", report::to_csv(&reports)),
+    }
+
+    if is_mem && is_text {
+        let memory: Vec<Memory> = reports
+            .iter()
+            .flat_map(|r| r.parse.iter().chain(&r.parts))
+            .filter_map(|p| p.memory)
+            .collect();
+        let peak_bytes = memory
+            .iter()
+            .map(|m| m.peak_bytes)
+            .max()
+            .unwrap_or_default();
+        let allocations = memory.iter().map(|m| m.allocations).sum::<u128>();
+
+        println!(
+            "\n{ANSI_BOLD}Memory:{ANSI_RESET} {ANSI_ITALIC}{} peak, {allocations} allocs{ANSI_RESET}",
+            memory::format_bytes(peak_bytes)
+        );
+    }
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
+        if is_release && !selection.is_all() {
+            eprintln!("Benchmarks are only saved when all days are run.");
+        } else if is_release {
+            match benchmarks::save(year, timings.clone()) {
+                Ok(path) => eprintln!("Saved benchmark baseline to \"{}\".", path.display()),
+                Err(e) => eprintln!("Failed to save benchmark baseline: {e:?}"),
+            }
+
+            match readme_benchmarks::update(year, timings, total_millis) {
+                Ok(()) => eprintln!("Successfully updated README with benchmarks."),
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
             }
         }
     }
+
+    let failures: Vec<String> = reports
+        .iter()
+        .flat_map(|r| {
+            r.failures().map(|p| {
+                format!(
+                    "Day {} {}: {}",
+                    r.day,
+                    report::part_label(p.part),
+                    p.error.as_deref().unwrap_or_default()
+                )
+            })
+        })
+        .collect();
+
+    if !failures.is_empty() {
+        eprintln!("\n{ANSI_BOLD}Failed:{ANSI_RESET}");
+        failures.iter().for_each(|failure| eprintln!("{failure}"));
+        process::exit(1);
+    }
+}
+
+/// Runs every registered solution of the year passed with `--year` in the current process.
+/// Only the days passed with `--days` are run if the argument is present.
+/// This is the entry point of the `all` binary.
+/// Results are reported through the results protocol, a panicking day does not stop the remaining days.
+pub fn run_in_process(solutions: &[&'static dyn Solution]) {
+    let year = get_year();
+    let days = get_days();
+
+    days.iter().enumerate().for_each(|(i, day)| {
+        if i > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let puzzle = PuzzleId::new(year, day);
+
+        let Some(solution) = registry::find(solutions, puzzle) else {
+            println!("Not solved.");
+            return;
+        };
+
+        protocol::emit_start(day);
+
+        let input_path = store::get_input_path(puzzle);
+        let input = match fs::read_to_string(&input_path) {
+            Ok(input) => input,
+            Err(e) => {
+                let error = format!("could not open input file \"{input_path}\": {e}");
+                eprintln!("{error}");
+                for part in 1..=2 {
+                    protocol::emit(
+                        day,
+                        &PartReport {
+                            part,
+                            answer: None,
+                            stats: Stats::default(),
+                            memory: None,
+                            error: Some(error.clone()),
+                        },
+                    );
+                }
+                return;
+            }
+        };
+
+        // the panic message has already been reported by the runner's panic hook.
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| solution.run(&input)));
+    });
+}
+
+/// Reads the year to run from the `--year` argument, falling back to `AOC_YEAR`.
+fn get_year() -> Year {
+    let args: Vec<String> = env::args().collect();
+
+    let year = match args.iter().position(|x| x == "--year") {
+        Some(index) => args.get(index + 1).and_then(|s| s.parse().ok()),
+        None => Year::from_env(),
+    };
+
+    year.unwrap_or_else(|| {
+        eprintln!("Unexpected command-line input. Format: --year 2023");
+        process::exit(1);
+    })
+}
+
+/// Reads the days to run from the `--days` argument, falling back to every day.
+fn get_days() -> DaySet {
+    let args: Vec<String> = env::args().collect();
+    let Some(index) = args.iter().position(|x| x == "--days") else {
+        return DaySet::all();
+    };
+
+    args.get(index + 1)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| {
+            eprintln!("Unexpected command-line input. Format: --days 1-10,15,20-");
+            process::exit(1);
+        })
+}
+
+/// Converts the results of a day into the timings shown in the readme.
+pub(crate) fn get_timings(report: &DayReport) -> Timings {
+    let get_part = |part: u8| {
+        report
+            .parts
+            .iter()
+            .find(|p| p.part == part && p.is_solved())
+    };
+
+    Timings {
+        day: report.day,
+        parse: report
+            .parse
+            .as_ref()
+            .filter(|p| p.is_solved())
+            .map(|p| p.stats),
+        part_1: get_part(1).map(|p| p.stats),
+        part_2: get_part(2).map(|p| p.stats),
+        memory_1: get_part(1).and_then(|p| p.memory),
+        memory_2: get_part(2).and_then(|p| p.memory),
+        #[allow(clippy::cast_precision_loss)]
+        total_nanos: report
+            .parse
+            .iter()
+            .chain(&report.parts)
+            .filter(|p| p.is_solved())
+            .map(|p| p.stats.median as f64)
+            .sum(),
+    }
 }
 
 #[derive(Debug)]
@@ -56,48 +344,184 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+impl From<protocol::Error> for Error {
+    fn from(e: protocol::Error) -> Self {
+        match e {
+            protocol::Error::Parser(s) => Error::Parser(s),
+            protocol::Error::IO(e) => Error::IO(e),
+        }
+    }
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
+/// Solutions are run by the `all` binary, which includes every scaffolded day, or by the binary of a single day.
+/// This module encapsulates invoking these binaries as well as collecting the results they report.
+pub(crate) mod child_commands {
+    use super::{is_scaffolded, Error};
+    use crate::template::report::{DayReport, PartReport, PARSE_PART};
+    use crate::template::stats::Stats;
+    use crate::template::{memory, protocol};
+    use crate::{Day, DaySet, PuzzleId, Year};
     use std::{
+        collections::BTreeMap,
+        env, fs,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        num::NonZeroUsize,
+        path::PathBuf,
+        process::{self, Command, ExitStatus, Stdio},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc,
+        },
         thread,
+        time::Duration,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
-
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    /// The solutions a child process runs.
+    #[derive(Debug, Clone, Copy)]
+    pub enum Target {
+        /// The binary of a single day.
+        Day(Day),
+        /// The `all` binary, restricted to a set of days.
+        Days(DaySet),
+    }
 
-        if is_release {
-            args.push("--release");
+    impl Target {
+        fn days(self) -> DaySet {
+            match self {
+                Target::Day(day) => [day].into_iter().collect(),
+                Target::Days(days) => days,
+            }
         }
+    }
+
+    /// Run the solutions of `target` in a child process and collect the results they report.
+    /// If the process stops early, e.g. because a part timed out, the remaining days are run in a new process.
+    /// Days without results have not been scaffolded yet and are returned as not run.
+    /// Output is only forwarded to stdout if `is_echoed` is set.
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_solutions(
+        year: Year,
+        target: Target,
+        is_timed: bool,
+        bench_time: Option<Duration>,
+        is_mem: bool,
+        timeout: Option<Duration>,
+        is_release: bool,
+        is_echoed: bool,
+    ) -> Result<Vec<DayReport>, Error> {
+        let mut options = vec![];
 
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            options.push("--time".into());
         }
 
+        if let Some(bench_time) = bench_time {
+            options.push("--bench-time".into());
+            options.push(format!("{}ns", bench_time.as_nanos()));
+        }
+
+        if is_mem {
+            options.push("--mem".into());
+        }
+
+        push_timeout(&mut options, timeout);
+
+        let mut remaining = target.days();
+        let mut results = vec![];
+        // the days a child process stopped in and its exit status.
+        let mut crashes = vec![];
+
+        // days that did not run because the child process stopped before starting any day, e.g. on a failed build.
+        let not_started = loop {
+            let mut args = match target {
+                Target::Day(day) => get_args(
+                    &PuzzleId::new(year, day).to_string(),
+                    year,
+                    is_release,
+                    is_mem,
+                ),
+                Target::Days(_) => {
+                    let mut args = get_args("all", year, is_release, is_mem);
+                    args.push("--days".into());
+                    args.push(remaining.to_string());
+                    args
+                }
+            };
+            args.extend(options.iter().cloned());
+
+            let (status, run) = run_child(&args, is_echoed)?;
+            results.extend(run.parts);
+
+            if status.success() {
+                break None;
+            }
+
+            let Some((crashed, rest)) = split_at_crash(remaining, &run.started) else {
+                break Some((remaining, status));
+            };
+
+            crashes.push((crashed, status));
+            remaining = rest;
+
+            if remaining.is_empty() {
+                break None;
+            }
+
+            if is_echoed {
+                println!();
+            }
+        };
+
+        Ok(target
+            .days()
+            .iter()
+            .map(|day| {
+                let mut report = collect_report(day, &results);
+
+                let status = crashes
+                    .iter()
+                    .find(|(d, _)| *d == day)
+                    .map(|(_, status)| *status)
+                    .or_else(|| {
+                        not_started
+                            .filter(|(days, _)| days.contains(day))
+                            .map(|(_, status)| status)
+                    });
+
+                if let Some(status) = status {
+                    check_exit_status(&mut report, year, status);
+                }
+
+                report
+            })
+            .collect())
+    }
+
+    /// Splits the days a child process was asked to run after it stopped unexpectedly
+    /// into the day it stopped in and the days that still have to run.
+    /// The day it stopped in is the last one it started, even if that day did not report any result.
+    /// Returns `None` if the child did not start any day.
+    fn split_at_crash(remaining: DaySet, started: &[Day]) -> Option<(Day, DaySet)> {
+        let crashed = started.last().copied()?;
+        let rest = remaining.iter().filter(|day| *day > crashed).collect();
+        Some((crashed, rest))
+    }
+
+    /// Run a child process, forwarding its output, and read the results it reported.
+    fn run_child(
+        args: &[String],
+        is_echoed: bool,
+    ) -> Result<(ExitStatus, protocol::Results), Error> {
+        let results_path = get_results_path(None);
+        let _ = fs::remove_file(&results_path);
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr, results are reported through the results file.
 
         let mut cmd = Command::new("cargo")
-            .args(&args)
+            .args(args)
+            .env(protocol::RESULTS_FILE_ENV, &results_path)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -105,8 +529,6 @@ mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
@@ -115,140 +537,353 @@ mod child_commands {
 
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+            if is_echoed {
+                println!("{line}");
+            }
         }
 
         thread.join().unwrap();
-        cmd.wait()?;
+        let status = cmd.wait()?;
+
+        let results = protocol::read_results(&results_path);
+        let _ = fs::remove_file(&results_path);
 
-        Ok(output)
+        Ok((status, results?))
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Mirrors `--timeout` to child invocations.
+    fn push_timeout(args: &mut Vec<String>, timeout: Option<Duration>) {
+        if let Some(timeout) = timeout {
+            args.push("--timeout".into());
+            args.push(format!("{}ns", timeout.as_nanos()));
+        }
+    }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+    /// Run every day of `days` in its own child process, up to `jobs` days at the same time.
+    /// The output of each day is buffered and printed in day order once all earlier days have finished.
+    /// Timing is not supported here: parallel runs compete for the CPU and would skew the benchmarks.
+    pub fn run_solutions_parallel(
+        year: Year,
+        days: DaySet,
+        jobs: NonZeroUsize,
+        is_mem: bool,
+        timeout: Option<Duration>,
+        is_release: bool,
+        is_echoed: bool,
+    ) -> Result<Vec<DayReport>, Error> {
+        // build once up front, so the children do not wait on each other for the build directory lock.
+        let mut build_args = vec![
+            "build".to_string(),
+            "--quiet".into(),
+            "--bin".into(),
+            "all".into(),
+        ];
+        if is_release {
+            build_args.push("--release".into());
+        }
+        if !Command::new("cargo").args(&build_args).status()?.success() {
+            return Err(Error::Parser("could not build the `all` binary.".into()));
+        }
+
+        let days: Vec<Day> = days.iter().collect();
+        let next = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..jobs.get().min(days.len()) {
+                let sender = sender.clone();
+                let (days, next) = (&days, &next);
+
+                scope.spawn(move || {
+                    while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)).copied() {
+                        let result = run_day(year, day, is_mem, timeout, is_release);
+                        if sender.send((day, result)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(sender);
+
+            let mut finished: BTreeMap<Day, DayRun> = BTreeMap::new();
+            let mut reports = Vec::with_capacity(days.len());
+
+            for (day, result) in receiver {
+                finished.insert(day, result?);
+
+                // print every day that is next in order.
+                while let Some(run) = days.get(reports.len()).and_then(|d| finished.remove(d)) {
+                    if is_echoed {
+                        if !reports.is_empty() {
+                            println!();
+                        }
+                        print!("{}", run.stdout);
+                    }
+                    eprint!("{}", run.stderr);
+                    reports.push(run.report);
                 }
+            }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+            Ok(reports)
+        })
+    }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+    /// The buffered output and the results of a day run by [`run_day`].
+    struct DayRun {
+        report: DayReport,
+        stdout: String,
+        stderr: String,
+    }
 
-                timings.total_nanos += nanos;
-            });
+    /// Run a single day with the `all` binary and buffer its output.
+    fn run_day(
+        year: Year,
+        day: Day,
+        is_mem: bool,
+        timeout: Option<Duration>,
+        is_release: bool,
+    ) -> Result<DayRun, Error> {
+        let mut args = get_args("all", year, is_release, is_mem);
+        args.push("--days".into());
+        args.push(day.into_inner().to_string());
+
+        if is_mem {
+            args.push("--mem".into());
+        }
+
+        push_timeout(&mut args, timeout);
+
+        let results_path = get_results_path(Some(day));
+        let _ = fs::remove_file(&results_path);
+
+        let output = Command::new("cargo")
+            .args(&args)
+            .env(protocol::RESULTS_FILE_ENV, &results_path)
+            .output()?;
+
+        let results = protocol::read_results(&results_path);
+        let _ = fs::remove_file(&results_path);
+
+        let mut report = collect_report(day, &results?.parts);
+        check_exit_status(&mut report, year, output.status);
+
+        Ok(DayRun {
+            report,
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+
+    /// Arguments to `cargo` that run `bin` for `year`, followed by the separator for the arguments of the binary.
+    fn get_args(bin: &str, year: Year, is_release: bool, is_mem: bool) -> Vec<String> {
+        let mut args = vec![
+            "run".to_string(),
+            "--quiet".into(),
+            "--bin".into(),
+            bin.into(),
+        ];
+
+        if is_release {
+            args.push("--release".into());
+        }
+
+        if is_mem {
+            // the counting allocator is only compiled in with this feature.
+            args.push("--features".into());
+            args.push(memory::FEATURE.into());
+        }
 
-        timings
+        args.push("--".into());
+        args.push("--year".into());
+        args.push(year.to_string());
+        args
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Path of the results file of a child process, unique per day when days run in parallel.
+    fn get_results_path(day: Option<Day>) -> PathBuf {
+        let name = match day {
+            Some(day) => format!("aoc-results-{}-{day}.jsonl", process::id()),
+            None => format!("aoc-results-{}.jsonl", process::id()),
+        };
+        env::temp_dir().join(name)
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    /// Records a failure for a scaffolded day that did not finish because its child process exited unsuccessfully,
+    /// e.g. after a stack overflow or a failed build. Days that reported a panic are left as they are.
+    fn check_exit_status(report: &mut DayReport, year: Year, status: ExitStatus) {
+        if status.success() || !is_scaffolded(PuzzleId::new(year, report.day)) {
+            return;
+        }
+
+        record_crash(
+            report,
+            &format!("the solution exited unexpectedly ({status})"),
+        );
+    }
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+    /// Records `error` as the failure of the first part of a day that did not report a result,
+    /// unless the day has finished or already reported a failure.
+    fn record_crash(report: &mut DayReport, error: &str) {
+        let is_finished = report.parts.len() >= 2 || report.failures().next().is_some();
 
-        Some((str_timing, parsed_timing))
+        if is_finished {
+            return;
+        }
+
+        let part = (1..=2)
+            .find(|part| report.parts.iter().all(|p| p.part != *part))
+            .unwrap_or(1);
+
+        report.ran = true;
+        report.parts.push(PartReport {
+            part,
+            answer: None,
+            stats: Stats::default(),
+            memory: None,
+            error: Some(error.into()),
+        });
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Assembles the report of a day from the records a child wrote, ordered by part.
+    /// A panic record replaces any result previously reported for the same part.
+    /// The record of the shared parse step is split off from the parts.
+    fn collect_report(day: Day, results: &[(Day, PartReport)]) -> DayReport {
+        let mut parts: Vec<PartReport> = vec![];
+        let ran = results.iter().any(|(d, _)| *d == day);
+
+        for (_, part) in results.iter().filter(|(d, _)| *d == day).cloned() {
+            match parts.iter_mut().find(|p| p.part == part.part) {
+                Some(existing) => *existing = part,
+                None => parts.push(part),
+            }
+        }
+
+        parts.sort_by_key(|p| p.part);
+
+        let parse = parts
+            .first()
+            .is_some_and(|p| p.part == PARSE_PART)
+            .then(|| parts.remove(0));
+
+        DayReport {
+            day,
+            ran,
+            parse,
+            parts,
+        }
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+        use super::{collect_report, record_crash, split_at_crash};
+        use crate::template::commands::all::get_timings;
+        use crate::template::protocol::{decode, encode};
+        use crate::template::report::PartReport;
+        use crate::template::stats::Stats;
+        use crate::{day, DaySet};
+
+        fn part(part: u8, answer: Option<&str>, nanos: u128, samples: u128) -> PartReport {
+            PartReport {
+                part,
+                answer: answer.map(Into::into),
+                stats: Stats {
+                    samples,
+                    median: nanos,
+                    ..Stats::default()
+                },
+                memory: None,
+                error: None,
+            }
+        }
 
         #[test]
         fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            let lines = [
+                encode(day!(1), &part(2, Some("10"), 74_130_000, 99_999)),
+                encode(day!(1), &part(1, Some("0"), 74, 100_000)),
+            ];
+            let results: Vec<_> = lines.iter().map(|l| decode(l).unwrap()).collect();
+            let res = collect_report(day!(1), &results);
+
+            assert_eq!(res.parts[0].answer.as_deref(), Some("0"));
+            assert_eq!(res.parts[1].answer.as_deref(), Some("10"));
+
+            let timings = get_timings(&res);
+            assert_eq!(timings.total_nanos, 74_130_074_f64);
+            assert_eq!(timings.part_1.unwrap().median, 74);
+            assert_eq!(timings.part_2.unwrap().median, 74_130_000);
+            assert_eq!(timings.part_2.unwrap().samples, 99_999);
         }
 
         #[test]
         fn test_patterns_in_input() {
-            let res = parse_exec_time(
+            let answer = "@ @ @ ( ) ms (2s @ 5 samples)";
+            let line = encode(day!(1), &part(1, Some(answer), 2_000_000_000, 5));
+            let res = collect_report(day!(1), &[decode(&line).unwrap()]);
+
+            assert_eq!(res.parts[0].answer.as_deref(), Some(answer));
+            assert_eq!(get_timings(&res).part_1.unwrap().median, 2_000_000_000);
+        }
+
+        #[test]
+        fn test_missing_parts() {
+            let res = collect_report(
+                day!(1),
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    (day!(1), part(1, None, 10, 1)),
+                    (day!(1), part(2, None, 10, 1)),
                 ],
-                day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            let timings = get_timings(&res);
+
+            assert_eq!(timings.total_nanos, 0_f64);
+            assert_eq!(timings.part_1.is_none(), true);
+            assert_eq!(timings.part_2.is_none(), true);
         }
 
         #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
+        fn test_panicked_parts() {
+            let mut panicked = part(2, None, 0, 0);
+            panicked.error = Some("No direction available".into());
+
+            let res = collect_report(
+                day!(10),
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    (day!(10), part(1, Some("4"), 10, 1)),
+                    (day!(10), panicked.clone()),
                 ],
-                day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+
+            assert_eq!(res.parts, vec![part(1, Some("4"), 10, 1), panicked]);
+            assert_eq!(res.is_solved(), false);
+        }
+
+        #[test]
+        fn test_abort_before_first_part() {
+            // day 2 aborted right after it started, before reporting any part.
+            let results = [
+                (day!(1), part(1, Some("1"), 10, 1)),
+                (day!(1), part(2, Some("2"), 10, 1)),
+            ];
+            let remaining: DaySet = "1-3".parse().unwrap();
+
+            let (crashed, rest) = split_at_crash(remaining, &[day!(1), day!(2)]).unwrap();
+            assert_eq!(crashed, day!(2));
+            assert_eq!(rest, "3".parse().unwrap());
+
+            let mut res = collect_report(crashed, &results);
+            assert_eq!(res.ran, false);
+            record_crash(&mut res, "the solution exited unexpectedly");
+            assert_eq!(res.ran, true);
+            assert_eq!(res.parts.len(), 1);
+            assert_eq!(res.parts[0].part, 1);
+            assert!(res.parts[0].error.is_some());
+
+            let mut finished = collect_report(day!(1), &results);
+            record_crash(&mut finished, "the solution exited unexpectedly");
+            assert_eq!(finished.is_solved(), true);
+
+            assert_eq!(split_at_crash(remaining, &[]), None);
         }
     }
 }
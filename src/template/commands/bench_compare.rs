@@ -0,0 +1,92 @@
+use std::process;
+use std::time::Duration;
+
+use crate::template::benchmarks::{self, Comparison};
+use crate::template::commands::all::{
+    child_commands::{self, Target},
+    get_timings,
+};
+use crate::template::readme_benchmarks::Timings;
+use crate::template::report;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::{DaySet, Year};
+
+/// Parts that get slower by more than this are reported as regressions, unless `--threshold` is passed.
+pub const DEFAULT_THRESHOLD: f64 = 0.25;
+
+pub fn handle(year: Year, baseline: Option<&str>, threshold: f64, bench_time: Option<Duration>) {
+    let baseline = match benchmarks::load(year, baseline) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("Failed to load benchmark baseline: {e:?}");
+            eprintln!("Baselines are recorded by running `cargo time`.");
+            process::exit(1);
+        }
+    };
+
+    let target = Target::Days(DaySet::all());
+
+    let reports = match child_commands::run_solutions(
+        year, target, true, bench_time, false, None, true, false,
+    ) {
+        Ok(reports) => reports,
+        Err(e) => {
+            eprintln!("Failed to run solutions: {e:?}");
+            process::exit(1);
+        }
+    };
+
+    let timings: Vec<Timings> = reports.iter().filter(|r| r.ran).map(get_timings).collect();
+
+    println!(
+        "Comparing against baseline {ANSI_BOLD}{}{ANSI_RESET} (regression threshold: {:.0}%)",
+        baseline.sha,
+        threshold * 100.0
+    );
+
+    let comparisons = benchmarks::compare(&baseline.timings, &timings);
+    let regressions: Vec<&Comparison> = comparisons
+        .iter()
+        .filter(|c| c.is_regression(threshold))
+        .collect();
+
+    for comparison in &comparisons {
+        let line = format!(
+            "Day {} {}: {}",
+            comparison.day,
+            report::part_label(comparison.part),
+            comparison.describe()
+        );
+        if comparison.is_regression(threshold) {
+            println!("{ANSI_BOLD}{line} ✖ regression{ANSI_RESET}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    if !regressions.is_empty() {
+        eprintln!("---");
+        let unsolved = regressions.iter().filter(|c| c.is_unsolved()).count();
+        eprintln!(
+            "{ANSI_ITALIC}{} part(s) got slower than the baseline by more than {:.0}%, {unsolved} part(s) are no longer solved.{ANSI_RESET}",
+            regressions.len() - unsolved,
+            threshold * 100.0
+        );
+        process::exit(1);
+    }
+}
+
+/// Parses a regression threshold given in percent, e.g. `25` or `25%`.
+pub fn parse_threshold(s: &str) -> Result<f64, String> {
+    let percent: f64 = s
+        .trim()
+        .trim_end_matches('%')
+        .parse()
+        .map_err(|_| format!("invalid threshold `{s}`, expected a percentage such as `25%`"))?;
+
+    if percent.is_finite() && percent >= 0.0 {
+        Ok(percent / 100.0)
+    } else {
+        Err(format!("threshold `{s}` must not be negative"))
+    }
+}
@@ -1,15 +1,108 @@
-use crate::template::aoc_cli;
-use crate::Day;
-use std::process;
+use crate::template::aoc_client::{self, AocClient};
+use crate::template::{examples, store};
+use crate::PuzzleId;
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+pub fn handle(puzzle: PuzzleId, force: bool) {
+    let fetch_input = force || !store::has_input(puzzle);
+    let fetch_puzzle = force || !store::has_puzzle(puzzle);
+
+    if !fetch_input && !fetch_puzzle {
+        println!(
+            "🎄 Input and puzzle are already present in \"{}\" and \"{}\", use --force to download them again.",
+            store::get_input_path(puzzle),
+            store::get_puzzle_path(puzzle)
+        );
+        apply_examples(puzzle);
+        return;
+    }
+
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to download: {e}");
+            process::exit(1);
+        }
+    };
+
+    if fetch_input {
+        let written = client
+            .input(puzzle)
+            .and_then(|input| store::write_input(puzzle, &input).map_err(store_error));
+        if let Err(e) = written {
+            eprintln!("Failed to download input: {e}");
+            process::exit(1);
+        }
+        println!(
+            "🎄 Successfully wrote input to \"{}\".",
+            store::get_input_path(puzzle)
+        );
+    }
+
+    if fetch_puzzle {
+        let written = client
+            .puzzle(puzzle)
+            .and_then(|description| store::write_puzzle(puzzle, &description).map_err(store_error));
+        if let Err(e) = written {
+            eprintln!("Failed to download puzzle: {e}");
+            process::exit(1);
+        }
+        println!(
+            "🎄 Successfully wrote puzzle to \"{}\".",
+            store::get_puzzle_path(puzzle)
+        );
+    }
+
+    if let Err(e) = store::record_fetch(puzzle) {
+        eprintln!(
+            "Failed to record download in \"{}\": {e:?}",
+            store::get_meta_path(puzzle)
+        );
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    apply_examples(puzzle);
+}
+
+/// Writes the examples found in the cached puzzle description to empty example files
+/// and fills their answers into the scaffolded tests of the solution.
+pub(crate) fn apply_examples(puzzle: PuzzleId) {
+    let Some(description) = store::read_puzzle(puzzle) else {
+        return;
     };
+
+    let examples = examples::extract(&description);
+
+    match examples::write(puzzle, &examples) {
+        Ok(paths) => {
+            for path in paths {
+                println!("🎄 Wrote example from the puzzle description to \"{path}\".");
+            }
+        }
+        Err(e) => eprintln!("Failed to write examples: {e}"),
+    }
+
+    let module_path = format!("src/bin/{puzzle}.rs");
+    let Ok(module) = fs::read_to_string(&module_path) else {
+        return;
+    };
+
+    let filled = examples::fill_tests(&module, &examples);
+    for warning in &filled.warnings {
+        eprintln!("Could not fill an example answer into \"{module_path}\", {warning}");
+    }
+
+    let filled = filled.module;
+    if filled != module {
+        match fs::write(&module_path, filled) {
+            Ok(()) => println!("🎄 Filled example answers into the tests of \"{module_path}\"."),
+            Err(e) => eprintln!("Failed to update module file: {e}"),
+        }
+    }
+}
+
+pub(crate) fn store_error(e: store::Error) -> aoc_client::Error {
+    match e {
+        store::Error::IO(e) => aoc_client::Error::IO(e),
+        store::Error::Parser(e) => aoc_client::Error::Parser(e),
+    }
 }
@@ -1,16 +1,41 @@
 use std::process;
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::aoc_client::AocClient;
+use crate::template::commands::download::store_error;
+use crate::template::{store, ANSI_ITALIC, ANSI_RESET};
+use crate::PuzzleId;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+pub fn handle(puzzle: PuzzleId) {
+    if let Some(description) = store::read_puzzle(puzzle) {
+        println!("{}", description.trim_end());
+
+        if let Ok(Some(meta)) = store::read_meta(puzzle) {
+            println!(
+                "\n{ANSI_ITALIC}Read from \"{}\", downloaded {}.{ANSI_RESET}",
+                store::get_puzzle_path(puzzle),
+                store::format_timestamp(meta.fetched_at)
+            );
+        }
+        return;
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let description = AocClient::from_env().and_then(|client| client.puzzle(puzzle));
+
+    let description = match description {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("Failed to read puzzle: {e}");
+            process::exit(1);
+        }
     };
+
+    println!("{}", description.trim_end());
+
+    let stored = store::write_puzzle(puzzle, &description)
+        .map_err(store_error)
+        .and_then(|()| store::record_fetch(puzzle).map(|_| ()).map_err(store_error));
+
+    if let Err(e) = stored {
+        eprintln!("Failed to store puzzle: {e}");
+    }
 }
@@ -1,18 +1,23 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::commands::download::apply_examples;
+use crate::template::templates::{self, Context};
+use crate::template::{data_dir, examples, store};
+use crate::PuzzleId;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+/// Template used unless `--template` is passed, see [`templates`] for the placeholders.
+pub(crate) const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!({{year}}, {{day}});
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Option<{{answer_type}}> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<{{answer_type}}> {
     None
 }
 
@@ -22,13 +27,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 }
@@ -39,13 +44,62 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Infers the answer type from the answers of the examples, if the puzzle description has been downloaded.
+fn infer_answer_type(description: Option<&str>) -> &'static str {
+    let is_large = description.is_some_and(|description| {
+        examples::extract(description).iter().any(|example| {
+            example
+                .answer
+                .as_deref()
+                .and_then(|answer| answer.parse::<u64>().ok())
+                .is_some_and(|answer| u32::try_from(answer).is_err())
+        })
+    });
+
+    if is_large {
+        "u64"
+    } else {
+        "u32"
+    }
+}
+
+pub fn handle(puzzle: PuzzleId, template: Option<&str>, answer_type: Option<&str>) {
+    let PuzzleId { year, day } = puzzle;
+    let input_path = format!("{}/{day}.txt", data_dir(year, "inputs"));
+    let example_path = format!("{}/{day}.txt", data_dir(year, "examples"));
+    let module_path = format!("src/bin/{puzzle}.rs");
+
+    let template = match template {
+        Some(name) => templates::load(name),
+        None => Ok(MODULE_TEMPLATE.to_string()),
+    };
+
+    let description = store::read_puzzle(puzzle);
+    let context = Context {
+        puzzle,
+        title: description
+            .as_deref()
+            .and_then(templates::get_title)
+            .unwrap_or_else(|| format!("Day {}", day.into_inner())),
+        answer_type: answer_type
+            .unwrap_or_else(|| infer_answer_type(description.as_deref()))
+            .to_string(),
+    };
+
+    let contents = match template.and_then(|template| templates::render(&template, &context)) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Failed to render template: {e}");
+            process::exit(1);
+        }
+    };
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -55,11 +109,7 @@ pub fn handle(day: Day) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(contents.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -69,6 +119,15 @@ pub fn handle(day: Day) {
         }
     }
 
+    for path in [&input_path, &example_path] {
+        if let Some(dir) = Path::new(path).parent() {
+            if let Err(e) = fs::create_dir_all(dir) {
+                eprintln!("Failed to create data directory: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
@@ -89,6 +148,8 @@ pub fn handle(day: Day) {
         }
     }
 
+    apply_examples(puzzle);
+
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
 }
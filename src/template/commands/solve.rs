@@ -1,14 +1,46 @@
+use std::io::{stdout, Write};
 use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
 
-use crate::Day;
+use crate::template::input::Input;
+use crate::template::memory;
+use crate::template::watch::{self, Snapshot};
+use crate::template::{ANSI_CLEAR, ANSI_ITALIC, ANSI_RESET};
+use crate::PuzzleId;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Interval between two checks for changed files in watch mode.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Options of `--watch`, which re-runs the solution whenever one of its files changes.
+pub struct Watch {
+    /// Whether the example tests are run before the solution.
+    pub tests: bool,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    puzzle: PuzzleId,
+    input: &Input,
+    release: bool,
+    time: bool,
+    bench_time: Option<Duration>,
+    mem: bool,
+    timeout: Option<Duration>,
+    submit_part: Option<u8>,
+    watch: Option<Watch>,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    if mem {
+        cmd_args.push("--features".to_string());
+        cmd_args.push(memory::FEATURE.to_string());
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -20,8 +52,31 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         cmd_args.push("--time".to_string());
     }
 
+    if let Some(bench_time) = bench_time {
+        cmd_args.push("--bench-time".to_string());
+        cmd_args.push(format!("{}ns", bench_time.as_nanos()));
+    }
+
+    if mem {
+        cmd_args.push("--mem".to_string());
+    }
+
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(format!("{}ns", timeout.as_nanos()));
+    }
+
+    cmd_args.extend(input.to_args());
+
+    match watch {
+        Some(watch) => watch_solution(puzzle, release, &watch, &cmd_args),
+        None => run_solution(&cmd_args),
+    }
+}
+
+fn run_solution(cmd_args: &[String]) {
     let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+        .args(cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
@@ -29,3 +84,57 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Runs the solution, then again every time its binary, the shared library or its input or examples change.
+fn watch_solution(puzzle: PuzzleId, release: bool, watch: &Watch, cmd_args: &[String]) {
+    let mut snapshot = Snapshot::take(watch::get_watched_files(puzzle));
+
+    loop {
+        print!("{ANSI_CLEAR}");
+        let _ = stdout().flush();
+
+        if !watch.tests || run_tests(puzzle, release) {
+            run_solution(cmd_args);
+        }
+
+        println!("\n{ANSI_ITALIC}Watching for changes, press Ctrl+C to stop.{ANSI_RESET}");
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let next = Snapshot::take(watch::get_watched_files(puzzle));
+            if !next.changes(&snapshot).is_empty() {
+                snapshot = next;
+                break;
+            }
+        }
+    }
+}
+
+/// Runs the example tests of a day and prints whether each passed.
+/// Returns `false` if the tests did not compile, the compiler errors are printed instead.
+fn run_tests(puzzle: PuzzleId, release: bool) -> bool {
+    let mut cmd_args = vec!["test".to_string(), "--bin".to_string(), puzzle.to_string()];
+
+    if release {
+        cmd_args.push("--release".to_string());
+    }
+
+    let output = match Command::new("cargo").args(&cmd_args).output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run tests: {e}");
+            return false;
+        }
+    };
+
+    let results = watch::parse_test_output(&String::from_utf8_lossy(&output.stdout));
+
+    if results.is_empty() && !output.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        return false;
+    }
+
+    println!("{}", watch::format_test_results(&results));
+    println!("---");
+    true
+}
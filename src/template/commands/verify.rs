@@ -0,0 +1,203 @@
+use std::process;
+
+use crate::template::answers::{self, Answers};
+use crate::template::commands::all::child_commands::{self, Target};
+use crate::template::report::DayReport;
+use crate::template::{data_dir, ANSI_BOLD, ANSI_RESET};
+use crate::{Day, DaySet, PuzzleId, Year};
+
+pub fn handle(year: Year, day: Option<Day>, is_release: bool) {
+    let target = day.map_or(Target::Days(DaySet::all()), Target::Day);
+
+    let reports = match child_commands::run_solutions(
+        year, target, false, None, false, None, is_release, false,
+    ) {
+        Ok(reports) => reports,
+        Err(e) => {
+            eprintln!("Failed to run solutions: {e:?}");
+            process::exit(1);
+        }
+    };
+
+    let mut checked = 0;
+    let mut failed = 0;
+
+    for report in &reports {
+        let puzzle = PuzzleId::new(year, report.day);
+        let expected = match answers::read(puzzle) {
+            Ok(expected) => expected,
+            Err(e) => {
+                eprintln!("Failed to read \"{}\": {e:?}", answers::get_path(puzzle));
+                failed += 1;
+                continue;
+            }
+        };
+
+        for check in compare(&expected, report) {
+            checked += 1;
+
+            if check.is_ok() {
+                println!("Day {} Part {}: ✔", report.day, check.part);
+                continue;
+            }
+
+            failed += 1;
+            println!(
+                "Day {} Part {}: {ANSI_BOLD}✖ answer changed{ANSI_RESET}",
+                report.day, check.part
+            );
+            print_diff(&check.expected, &check.actual);
+        }
+    }
+
+    if checked == 0 {
+        println!(
+            "No recorded answers found, accepted submissions are recorded in \"{}\".",
+            data_dir(year, "answers")
+        );
+    }
+
+    if failed > 0 {
+        eprintln!("---");
+        eprintln!("{failed} answer(s) differ from the recorded answers.");
+        process::exit(1);
+    }
+}
+
+/// The outcome of checking one part against its recorded answer.
+/// `actual` holds the error message if the part did not produce an answer.
+#[derive(Debug, PartialEq)]
+struct Check {
+    part: u8,
+    expected: String,
+    actual: Result<String, String>,
+}
+
+impl Check {
+    fn is_ok(&self) -> bool {
+        self.actual.as_deref() == Ok(self.expected.as_str())
+    }
+}
+
+/// Checks every part with a recorded answer against the results of a run.
+fn compare(expected: &Answers, report: &DayReport) -> Vec<Check> {
+    (1..=2)
+        .filter_map(|part| {
+            let expected = expected.get(part)?;
+            let actual = report
+                .parts
+                .iter()
+                .find(|p| p.part == part)
+                .ok_or_else(
+                    || match report.parse.as_ref().and_then(|p| p.error.clone()) {
+                        Some(error) => format!("parse failed: {error}"),
+                        None => "part did not run".to_string(),
+                    },
+                )
+                .and_then(|p| {
+                    p.answer
+                        .clone()
+                        .ok_or_else(|| p.error.clone().unwrap_or_else(|| "no answer".into()))
+                });
+
+            Some(Check {
+                part,
+                expected: expected.into(),
+                actual,
+            })
+        })
+        .collect()
+}
+
+fn print_diff(expected: &str, actual: &Result<String, String>) {
+    for line in expected.lines() {
+        println!("  - {line}");
+    }
+
+    match actual {
+        Ok(actual) => {
+            for line in actual.lines() {
+                println!("  + {line}");
+            }
+        }
+        Err(e) => println!("  + <{e}>"),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, Check};
+    use crate::template::answers::Answers;
+    use crate::template::report::{DayReport, PartReport};
+    use crate::template::stats::Stats;
+    use crate::{day, IntoAnswer};
+
+    #[test]
+    fn compares_recorded_parts() {
+        let expected = Answers {
+            part_one: Some("142".into()),
+            part_two: Some("281".into()),
+        };
+        let report = DayReport {
+            day: day!(1),
+            ran: true,
+            parse: None,
+            parts: vec![PartReport {
+                part: 1,
+                answer: Some("143".into()),
+                stats: Stats::default(),
+                memory: None,
+                error: None,
+            }],
+        };
+
+        assert_eq!(
+            compare(&expected, &report),
+            vec![
+                Check {
+                    part: 1,
+                    expected: "142".into(),
+                    actual: Ok("143".into()),
+                },
+                Check {
+                    part: 2,
+                    expected: "281".into(),
+                    actual: Err("part did not run".into()),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_submitted_text() {
+        let expected = Answers {
+            part_one: Some("ABC".into()),
+            part_two: None,
+        };
+        let report = DayReport {
+            day: day!(1),
+            ran: true,
+            parse: None,
+            parts: vec![PartReport {
+                part: 1,
+                answer: Some("ABC\n".into_answer().to_report()),
+                stats: Stats::default(),
+                memory: None,
+                error: None,
+            }],
+        };
+
+        assert!(compare(&expected, &report).iter().all(Check::is_ok));
+    }
+
+    #[test]
+    fn skips_unrecorded_parts() {
+        let report = DayReport {
+            day: day!(1),
+            ran: false,
+            parse: None,
+            parts: vec![],
+        };
+        assert_eq!(compare(&Answers::default(), &report), vec![]);
+    }
+}
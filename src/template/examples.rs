@@ -0,0 +1,362 @@
+/// Module that extracts example inputs and answers from downloaded puzzle descriptions.
+/// The first code block of a part is taken as its example input, the last emphasised code as its answer.
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::template::data_dir;
+use crate::PuzzleId;
+
+/// Heading that starts the second part of a puzzle description.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// The example of one part of a puzzle.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The example input, `None` if the part reuses the example of part one.
+    pub input: Option<String>,
+    /// The answer for the example input.
+    pub answer: Option<String>,
+}
+
+/// Returns the contents of every fenced code block in a markdown document.
+fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut current: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.trim_end() == "```" {
+            match current.take() {
+                Some(block) => blocks.push(block),
+                None => current = Some(String::new()),
+            }
+        } else if let Some(block) = current.as_mut() {
+            block.push_str(line);
+            block.push('\n');
+        }
+    }
+
+    blocks
+}
+
+/// Returns every emphasised inline code span outside of code blocks, e.g. `142` for `` `*142*` ``.
+fn emphasised_code(markdown: &str) -> Vec<String> {
+    let mut spans = vec![];
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_end() == "```" {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some(start) = rest.find("`*") {
+            rest = &rest[start + 2..];
+            let Some(end) = rest.find("*`") else {
+                break;
+            };
+            spans.push(rest[..end].to_string());
+            rest = &rest[end + 2..];
+        }
+    }
+
+    spans
+}
+
+/// Extracts the examples of both parts from a puzzle description.
+/// Part two only gets an input if its first code block differs from every code block of part one.
+#[must_use]
+pub fn extract(markdown: &str) -> [Example; 2] {
+    let (part_one, part_two) = markdown
+        .split_once(PART_TWO_HEADING)
+        .unwrap_or((markdown, ""));
+
+    let blocks_one = code_blocks(part_one);
+    let blocks_two = code_blocks(part_two);
+
+    [
+        Example {
+            input: blocks_one.first().cloned(),
+            answer: emphasised_code(part_one).pop(),
+        },
+        Example {
+            input: blocks_two
+                .first()
+                .filter(|block| !blocks_one.contains(block))
+                .cloned(),
+            answer: emphasised_code(part_two).pop(),
+        },
+    ]
+}
+
+/// Returns the path of the example file of a part, e.g. `data/2023/examples/01-2.txt` for part two.
+#[must_use]
+pub fn get_path(puzzle: PuzzleId, part: u8) -> String {
+    let dir = data_dir(puzzle.year, "examples");
+    match part {
+        1 => format!("{dir}/{}.txt", puzzle.day),
+        _ => format!("{dir}/{}-{part}.txt", puzzle.day),
+    }
+}
+
+/// Writes the extracted example inputs, unless the example files already have content.
+/// Returns the paths of the written files.
+pub fn write(puzzle: PuzzleId, examples: &[Example; 2]) -> Result<Vec<String>, io::Error> {
+    let mut written = vec![];
+
+    for (part, example) in (1..).zip(examples) {
+        let Some(input) = &example.input else {
+            continue;
+        };
+
+        let path = get_path(puzzle, part);
+        if fs::read_to_string(&path).is_ok_and(|s| !s.trim().is_empty()) {
+            continue;
+        }
+
+        if let Some(dir) = Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, input)?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// A solution module with the example answers filled into its tests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilledTests {
+    pub module: String,
+    /// Why the answers of some parts could not be filled in, e.g. for modules scaffolded from a custom template.
+    pub warnings: Vec<String>,
+}
+
+/// Returns the expression a part's result is compared against and the return type the answer needs,
+/// `None` if the answer fits into an `u32`.
+fn answer_literal(answer: &str) -> (String, Option<&'static str>) {
+    if let Ok(answer) = answer.parse::<u64>() {
+        let answer_type = u32::try_from(answer).is_err().then_some("u64");
+        (format!("Some({answer})"), answer_type)
+    } else if let Ok(answer) = answer.parse::<i64>() {
+        (format!("Some({answer})"), Some("i64"))
+    } else {
+        (format!("Some({answer:?})"), Some("String"))
+    }
+}
+
+/// Fills the example answers into the tests of a solution module that still has the scaffolded assertions.
+/// Answers that do not fit into an `u32` change the return type of the part, to `u64`, `i64` or `String` for text.
+/// A part with its own example input reads it with `read_file_part`.
+#[must_use]
+pub fn fill_tests(module: &str, examples: &[Example; 2]) -> FilledTests {
+    let mut module = module.to_string();
+    let mut warnings = vec![];
+
+    for ((part, name), example) in [(1, "one"), (2, "two")].into_iter().zip(examples) {
+        let test_fn = format!("fn test_part_{name}()");
+        let Some(start) = module.find(&test_fn) else {
+            if let Some(answer) = &example.answer {
+                warnings.push(format!(
+                    "part {part}: no `{test_fn}` to fill in the answer `{answer}`."
+                ));
+            }
+            continue;
+        };
+        let end = module[start..]
+            .find("\n    }\n")
+            .map_or(module.len(), |end| start + end);
+
+        let mut test = module[start..end].to_string();
+
+        if part > 1 && example.input.is_some() {
+            test = test.replace(
+                "read_file(\"examples\", PUZZLE)",
+                &format!("read_file_part(\"examples\", PUZZLE, {part})"),
+            );
+        }
+
+        let Some(answer) = &example.answer else {
+            module.replace_range(start..end, &test);
+            continue;
+        };
+
+        let (literal, answer_type) = answer_literal(answer);
+        // text answers are compared as `&str`, so the test does not need to allocate.
+        let assertion = match answer_type {
+            Some("String") => format!("assert_eq!(result.as_deref(), {literal});"),
+            _ => format!("assert_eq!(result, {literal});"),
+        };
+
+        if test.contains("assert_eq!(result, None);") {
+            test = test.replace("assert_eq!(result, None);", &assertion);
+        } else if !test.contains(&literal) {
+            warnings.push(format!(
+                "part {part}: `{test_fn}` has no `assert_eq!(result, None);` to fill in the answer `{answer}`."
+            ));
+        }
+
+        module.replace_range(start..end, &test);
+
+        if let Some(answer_type) = answer_type {
+            let signature = format!("pub fn part_{name}(input: &str) -> Option<");
+            let current = module
+                .find(&signature)
+                .map(|i| &module[i + signature.len()..])
+                .and_then(|rest| rest.split_once('>'))
+                .map(|(current, _)| current.to_string());
+
+            match current.as_deref() {
+                Some(current) if current == answer_type => {}
+                // the numeric types scaffolding picks, see `infer_answer_type`.
+                Some(current @ ("u32" | "u64")) => {
+                    module = module.replacen(
+                        &format!("{signature}{current}>"),
+                        &format!("{signature}{answer_type}>"),
+                        1,
+                    );
+                }
+                _ => warnings.push(format!(
+                    "part {part}: the answer `{answer}` needs `part_{name}` to return `Option<{answer_type}>`."
+                )),
+            }
+        }
+    }
+
+    FilledTests { module, warnings }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, fill_tests, Example};
+    use crate::template::commands::scaffold::MODULE_TEMPLATE;
+    use crate::template::templates::{render, Context};
+    use crate::{day, year, PuzzleId};
+
+    fn scaffolded() -> String {
+        let context = Context {
+            puzzle: PuzzleId::new(year!(2023), day!(1)),
+            title: "Day 1: Trebuchet?!".into(),
+            answer_type: "u32".into(),
+        };
+        render(MODULE_TEMPLATE, &context).unwrap()
+    }
+
+    fn puzzle() -> String {
+        [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "treb7uchet",
+            "```",
+            "",
+            "The values are `12` and `77`. Adding these together produces `*89*`.",
+            "",
+            "## --- Part Two ---",
+            "",
+            "Digits may be *spelled out*, for example:",
+            "",
+            "```",
+            "two1nine",
+            "eightwothree",
+            "```",
+            "",
+            "```",
+            "29",
+            "83",
+            "```",
+            "",
+            "Adding these together produces `*112*`, after trying `*1*` above.",
+            "Actually the answer is `*5000000000*`.",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let [one, two] = extract(&puzzle());
+        assert_eq!(one.input.as_deref(), Some("1abc2\ntreb7uchet\n"));
+        assert_eq!(one.answer.as_deref(), Some("89"));
+        assert_eq!(two.input.as_deref(), Some("two1nine\neightwothree\n"));
+        assert_eq!(two.answer.as_deref(), Some("5000000000"));
+    }
+
+    #[test]
+    fn reuses_example_of_part_one() {
+        let markdown = "```\n1\n```\n`*1*`\n## --- Part Two ---\n```\n1\n```\nnow `*2*`";
+        let [_, two] = extract(markdown);
+        assert_eq!(two.input, None);
+        assert_eq!(two.answer.as_deref(), Some("2"));
+        assert_eq!(
+            extract("no examples"),
+            [Example::default(), Example::default()]
+        );
+    }
+
+    #[test]
+    fn fills_tests() {
+        let module = fill_tests(&scaffolded(), &extract(&puzzle())).module;
+
+        assert!(module.contains("pub fn part_one(input: &str) -> Option<u32>"));
+        assert!(module.contains("pub fn part_two(input: &str) -> Option<u64>"));
+        assert!(module.contains("assert_eq!(result, Some(89));"));
+        assert!(module.contains("assert_eq!(result, Some(5000000000));"));
+        assert!(module.contains("read_file_part(\"examples\", PUZZLE, 2)"));
+        assert!(!module.contains("None);"));
+
+        let unchanged = fill_tests(&scaffolded(), &[Example::default(), Example::default()]);
+        assert_eq!(unchanged.module, scaffolded());
+        assert!(unchanged.warnings.is_empty());
+    }
+
+    #[test]
+    fn fills_text_and_negative_answers() {
+        let example = |answer: &str| Example {
+            input: None,
+            answer: Some(answer.into()),
+        };
+        let filled = fill_tests(&scaffolded(), &[example("-12"), example("LJ\"X")]);
+
+        assert!(filled.warnings.is_empty());
+        assert!(filled
+            .module
+            .contains("pub fn part_one(input: &str) -> Option<i64>"));
+        assert!(filled.module.contains("assert_eq!(result, Some(-12));"));
+        assert!(filled
+            .module
+            .contains("pub fn part_two(input: &str) -> Option<String>"));
+        assert!(filled
+            .module
+            .contains(r#"assert_eq!(result.as_deref(), Some("LJ\"X"));"#));
+    }
+
+    #[test]
+    fn warns_about_unfilled_answers() {
+        let custom = scaffolded()
+            .replace("assert_eq!(result, None);", "assert!(result.is_none());")
+            .replace("-> Option<u32>", "-> Option<usize>");
+        let examples = [
+            Example {
+                input: None,
+                answer: Some("7".into()),
+            },
+            Example {
+                input: None,
+                answer: Some("ABC".into()),
+            },
+        ];
+        let filled = fill_tests(&custom, &examples);
+
+        assert_eq!(filled.module, custom);
+        assert_eq!(filled.warnings.len(), 3);
+        assert!(filled.warnings[2].contains("Option<String>"));
+        assert_eq!(fill_tests("", &examples).warnings.len(), 2);
+    }
+}
@@ -0,0 +1,263 @@
+/// Minimal conversion of the HTML served by adventofcode.com to markdown.
+/// Only handles the handful of tags used in puzzle descriptions and answer responses.
+use std::fmt::Write;
+
+/// A tag or a piece of text of an HTML document.
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Splits an HTML document into tags and text. Comments and doctypes are dropped.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let tag = &rest[start..];
+        if tag.starts_with("<!--") {
+            rest = tag.find("-->").map_or("", |end| &tag[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = tag.find('>') else {
+            tokens.push(Token::Text(tag));
+            break;
+        };
+
+        let inner = tag[1..end].trim_end_matches('/').trim();
+        rest = &tag[end + 1..];
+
+        if let Some(name) = inner.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else if !inner.starts_with('!') {
+            let (name, attrs) = inner.split_once(char::is_whitespace).unwrap_or((inner, ""));
+            tokens.push(Token::Open(name, attrs));
+        }
+    }
+
+    tokens
+}
+
+/// Reads the value of an attribute, e.g. `href` of `href="/2023/day/1"`.
+fn get_attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = attrs[start..].find('"')?;
+    Some(&attrs[start..start + len])
+}
+
+/// Decodes the character references used on adventofcode.com.
+#[must_use]
+pub fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let c = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => name
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| name.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        });
+
+        match (c, entity) {
+            (Some(c), Some((_, end))) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Converts an HTML fragment to markdown.
+#[must_use]
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open("h2", _) => out.push_str("\n\n## "),
+            Token::Close("h2" | "p" | "ul") => out.push_str("\n\n"),
+            Token::Open("p" | "ul", _) => out.push_str("\n\n"),
+            Token::Open("li", _) => out.push_str("\n- "),
+            Token::Open("br", _) => out.push('\n'),
+            Token::Open("pre", _) => {
+                in_pre = true;
+                out.push_str("\n\n```\n");
+            }
+            Token::Close("pre") => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            Token::Open("code", _) | Token::Close("code") if !in_pre => out.push('`'),
+            Token::Open("em", _) | Token::Close("em") if !in_pre => out.push('*'),
+            Token::Open("a", attrs) => {
+                links.push(get_attr(attrs, "href").unwrap_or_default().to_string());
+                out.push('[');
+            }
+            Token::Close("a") => {
+                let href = links.pop().unwrap_or_default();
+                let _ = write!(out, "]({href})");
+            }
+            Token::Text(text) if in_pre => out.push_str(&decode_entities(text)),
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                let mut words = text.split_whitespace().peekable();
+                if text.starts_with(char::is_whitespace) && words.peek().is_some() {
+                    out.push(' ');
+                }
+                while let Some(word) = words.next() {
+                    out.push_str(word);
+                    if words.peek().is_some() {
+                        out.push(' ');
+                    }
+                }
+                if text.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+                    out.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // collapse the blank lines introduced by adjacent block elements.
+    let lines: Vec<&str> = out.lines().map(str::trim_end).collect();
+    let mut markdown = String::new();
+    let mut in_code_block = false;
+
+    for (i, line) in lines.iter().enumerate() {
+        if *line == "```" {
+            in_code_block = !in_code_block;
+        }
+        let is_blank = line.trim().is_empty();
+        if !in_code_block && is_blank && (i == 0 || lines[i - 1].trim().is_empty()) {
+            continue;
+        }
+        markdown.push_str(if in_code_block {
+            line
+        } else {
+            line.trim_start()
+        });
+        markdown.push('\n');
+    }
+
+    markdown.trim().to_string()
+}
+
+/// Returns the contents of every element named `tag` whose opening tag contains `attrs`.
+#[must_use]
+pub fn find_elements<'a>(html: &'a str, tag: &str, attrs: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let mut elements = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(&open) {
+        let after = rest[start + open.len()..].chars().next();
+        if !after.is_some_and(|c| c == '>' || c.is_whitespace()) {
+            rest = &rest[start + open.len()..];
+            continue;
+        }
+
+        let Some(tag_end) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let is_match = rest[start..tag_end].contains(attrs);
+        let Some(end) = rest[tag_end..].find(&close).map(|i| tag_end + i) else {
+            break;
+        };
+        if is_match {
+            elements.push(&rest[tag_end..end]);
+        }
+        rest = &rest[end + close.len()..];
+    }
+
+    elements
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_entities, find_elements, to_markdown};
+
+    #[test]
+    fn converts_puzzle_descriptions() {
+        let html = concat!(
+            "<h2>--- Day 1: Trebuchet?! ---</h2><p>Something is <em>wrong</em> with\n",
+            "<a href=\"/2023/about\">global snow</a>.</p>\n",
+            "<pre><code>1abc2\n<em>pqr3stu8vwx</em>\n</code></pre>\n",
+            "<p>In this example, the values are <code>12</code>. Adding these produces ",
+            "<code><em>142</em></code>.</p><ul><li>one</li><li>two &amp; three</li></ul>"
+        );
+
+        assert_eq!(
+            to_markdown(html),
+            [
+                "## --- Day 1: Trebuchet?! ---",
+                "",
+                "Something is *wrong* with [global snow](/2023/about).",
+                "",
+                "```",
+                "1abc2",
+                "pqr3stu8vwx",
+                "```",
+                "",
+                "In this example, the values are `12`. Adding these produces `*142*`.",
+                "",
+                "- one",
+                "- two & three",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(decode_entities("&lt;x&gt; &amp;&#39;&#x27;"), "<x> &''");
+        assert_eq!(decode_entities("a & b &unknown;"), "a & b &unknown;");
+    }
+
+    #[test]
+    fn finds_elements() {
+        let html = "<main><article class=\"day-desc\">one</article><p>x</p><article class=\"day-desc\"><p>two</p></article></main>";
+        assert_eq!(
+            find_elements(html, "article", "day-desc"),
+            vec!["one", "<p>two</p>"]
+        );
+        assert_eq!(find_elements(html, "p", ""), vec!["x", "two"]);
+    }
+}
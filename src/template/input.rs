@@ -0,0 +1,191 @@
+/// Module that resolves the input a solution binary runs against.
+/// Solutions read `data/<year>/inputs/<day>.txt`, unless `--input <path>`, `--input -` or `--example [N]` is passed.
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::str::FromStr;
+use std::{env, fs, process};
+
+use crate::template::{examples, store};
+use crate::PuzzleId;
+
+/// Where the input of a solution comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Input {
+    /// The downloaded puzzle input.
+    #[default]
+    Puzzle,
+    /// A file passed with `--input <path>`.
+    Path(String),
+    /// Standard input, passed as `--input -`.
+    Stdin,
+    /// The n-th example of the day passed with `--example [N]`, e.g. `01-2.txt` for the second one.
+    Example(u8),
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read, with the path it was resolved to.
+    File(String, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::File(path, e) => write!(f, "could not open input file \"{path}\": {e}"),
+            Error::Stdin(e) => write!(f, "could not read input from stdin: {e}"),
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = String;
+
+    /// Parses the value of `--input`, where `-` stands for stdin.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("expecting a path or `-` for stdin".into()),
+            "-" => Ok(Input::Stdin),
+            path => Ok(Input::Path(path.into())),
+        }
+    }
+}
+
+impl Input {
+    /// Reads the input from the `--input` and `--example` arguments.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let value_of = |flag: &str| {
+            let index = args.iter().position(|x| x == flag)?;
+            Some(args.get(index + 1).map(String::as_str))
+        };
+
+        match (value_of("--input"), value_of("--example")) {
+            (Some(_), Some(_)) => Err("`--input` can not be combined with `--example`.".into()),
+            (Some(path), None) => path.unwrap_or_default().parse(),
+            (None, Some(n)) => match n.filter(|n| !n.starts_with("--")) {
+                Some(n) => n
+                    .parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .map(Input::Example)
+                    .ok_or_else(|| "expecting an example number like `--example 2`.".into()),
+                None => Ok(Input::Example(1)),
+            },
+            (None, None) => Ok(Input::Puzzle),
+        }
+    }
+
+    /// Returns the arguments that select this input, to forward them to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Input::Puzzle => vec![],
+            Input::Path(path) => vec!["--input".into(), path.clone()],
+            Input::Stdin => vec!["--input".into(), "-".into()],
+            Input::Example(n) => vec!["--example".into(), n.to_string()],
+        }
+    }
+
+    /// Returns the path of the input file, `None` for stdin.
+    #[must_use]
+    pub fn get_path(&self, puzzle: PuzzleId) -> Option<String> {
+        match self {
+            Input::Puzzle => Some(store::get_input_path(puzzle)),
+            Input::Path(path) => Some(path.clone()),
+            Input::Stdin => None,
+            Input::Example(n) => Some(examples::get_path(puzzle, *n)),
+        }
+    }
+
+    /// Reads the input of a puzzle.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<String, Error> {
+        match self.get_path(puzzle) {
+            Some(path) => fs::read_to_string(&path).map_err(|e| Error::File(path, e)),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(Error::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Reads the input selected by the arguments of the current process.
+/// Exits with an error naming the resolved path if it can not be read, this is used by the `solution!` macro.
+#[must_use]
+pub fn read_from_args(puzzle: PuzzleId) -> String {
+    let args: Vec<String> = env::args().collect();
+
+    let input = Input::from_args(&args).unwrap_or_else(|e| {
+        eprintln!("Unexpected command-line input: {e}");
+        process::exit(1);
+    });
+
+    input.read(puzzle).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        if input == Input::Puzzle {
+            eprintln!(
+                "Run `cargo download {} --year {}` to download it.",
+                puzzle.day, puzzle.year
+            );
+        }
+        process::exit(1);
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Input;
+    use crate::{day, year, PuzzleId};
+
+    fn parse(args: &[&str]) -> Result<Input, String> {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        Input::from_args(&args)
+    }
+
+    #[test]
+    fn parses_inputs() {
+        assert_eq!(parse(&["05"]), Ok(Input::Puzzle));
+        assert_eq!(parse(&["--input", "-"]), Ok(Input::Stdin));
+        assert_eq!(
+            parse(&["--input", "in.txt", "--time"]),
+            Ok(Input::Path("in.txt".into()))
+        );
+        assert_eq!(parse(&["--example"]), Ok(Input::Example(1)));
+        assert_eq!(parse(&["--example", "2"]), Ok(Input::Example(2)));
+
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--example", "0"]).is_err());
+        assert!(parse(&["--example", "two"]).is_err());
+        assert_eq!(parse(&["--example", "--time"]), Ok(Input::Example(1)));
+        assert!(parse(&["--input", "a", "--example"]).is_err());
+
+        for input in [Input::Stdin, Input::Example(2), Input::Path("a".into())] {
+            let args = input.to_args();
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+            assert_eq!(parse(&args), Ok(input));
+        }
+    }
+
+    #[test]
+    fn resolves_paths() {
+        let puzzle = PuzzleId::new(year!(2023), day!(5));
+
+        assert_eq!(
+            Input::Puzzle.get_path(puzzle).as_deref(),
+            Some("data/2023/inputs/05.txt")
+        );
+        assert_eq!(
+            Input::Example(2).get_path(puzzle).as_deref(),
+            Some("data/2023/examples/05-2.txt")
+        );
+        assert_eq!(Input::Stdin.get_path(puzzle), None);
+
+        let error = Input::Path("missing.txt".into()).read(puzzle).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("could not open input file \"missing.txt\""));
+    }
+}
@@ -0,0 +1,410 @@
+/// Minimal JSON value type used for the machine-readable output of the template.
+/// Only covers what the template needs, no external dependencies.
+use std::fmt::{Display, Write};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    /// A non-negative integer, kept exact since durations and byte counts do not fit an `f64` losslessly.
+    Int(u128),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Creates an empty object that fields can be pushed to with [`Json::with`].
+    #[must_use]
+    pub fn object() -> Self {
+        Json::Object(vec![])
+    }
+
+    /// Appends a field to an object. Has no effect on other variants.
+    #[must_use]
+    pub fn with(mut self, key: &str, value: impl Into<Json>) -> Self {
+        if let Json::Object(fields) = &mut self {
+            fields.push((key.into(), value.into()));
+        }
+        self
+    }
+
+    /// Parses a JSON document.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser {
+            chars: s.chars().peekable(),
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        match parser.chars.next() {
+            None => Ok(value),
+            Some(c) => Err(ParseError(format!("unexpected trailing character `{c}`"))),
+        }
+    }
+
+    /// Returns the value of a field if this is an object.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Int(n) => Some(*n as f64),
+            Json::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a non-negative integer. Integral floats are accepted as well, e.g. `1e3`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            Json::Int(n) => Some(*n),
+            Json::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Some(*n as u128),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Json::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// An error which can be returned when parsing a JSON document.
+#[derive(Debug, PartialEq)]
+pub struct ParseError(String);
+
+impl std::error::Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid json: {}", self.0)
+    }
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        match self.chars.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(ParseError(format!("expected `{expected}`, found `{c}`"))),
+            None => Err(ParseError(format!(
+                "expected `{expected}`, found end of input"
+            ))),
+        }
+    }
+
+    fn literal(&mut self, literal: &str, value: Json) -> Result<Json, ParseError> {
+        for c in literal.chars() {
+            self.expect(c)?;
+        }
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Json, ParseError> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('n') => self.literal("null", Json::Null),
+            Some('t') => self.literal("true", Json::Bool(true)),
+            Some('f') => self.literal("false", Json::Bool(false)),
+            Some('"') => self.string().map(Json::String),
+            Some('[') => self.array(),
+            Some('{') => self.object(),
+            Some(c) if *c == '-' || c.is_ascii_digit() => self.number(),
+            Some(c) => Err(ParseError(format!("unexpected character `{c}`"))),
+            None => Err(ParseError("unexpected end of input".into())),
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, ParseError> {
+        let mut s = String::new();
+        while let Some(c) = self
+            .chars
+            .next_if(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            s.push(c);
+        }
+        if let Ok(n) = s.parse() {
+            return Ok(Json::Int(n));
+        }
+        s.parse()
+            .map(Json::Number)
+            .map_err(|_| ParseError(format!("invalid number `{s}`")))
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(s),
+                Some('\\') => match self.chars.next() {
+                    Some('"') => s.push('"'),
+                    Some('\\') => s.push('\\'),
+                    Some('/') => s.push('/'),
+                    Some('n') => s.push('\n'),
+                    Some('r') => s.push('\r'),
+                    Some('t') => s.push('\t'),
+                    Some('b') => s.push('\u{8}'),
+                    Some('f') => s.push('\u{c}'),
+                    Some('u') => s.push(self.unicode_escape()?),
+                    _ => return Err(ParseError("invalid escape sequence".into())),
+                },
+                Some(c) => s.push(c),
+                None => return Err(ParseError("unterminated string".into())),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, ParseError> {
+        let hex: String = self.chars.by_ref().take(4).collect();
+        u32::from_str_radix(&hex, 16).map_err(|_| ParseError(format!("invalid escape `{hex}`")))
+    }
+
+    fn unicode_escape(&mut self) -> Result<char, ParseError> {
+        let code = self.hex4()?;
+        let code = if (0xD800..0xDC00).contains(&code) {
+            // surrogate pair, the low half follows as another escape.
+            self.expect('\\')?;
+            self.expect('u')?;
+            let low = self.hex4()?;
+            0x10000 + ((code - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF)
+        } else {
+            code
+        };
+        char::from_u32(code).ok_or_else(|| ParseError(format!("invalid code point `{code}`")))
+    }
+
+    fn array(&mut self) -> Result<Json, ParseError> {
+        self.expect('[')?;
+        let mut items = vec![];
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&']').is_some() {
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(Json::Array(items)),
+                _ => return Err(ParseError("expected `,` or `]` in array".into())),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, ParseError> {
+        self.expect('{')?;
+        let mut fields = vec![];
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&'}').is_some() {
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(Json::Object(fields)),
+                _ => return Err(ParseError("expected `,` or `}` in object".into())),
+            }
+        }
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.into())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl From<u8> for Json {
+    fn from(value: u8) -> Self {
+        Json::Int(value.into())
+    }
+}
+
+impl From<f64> for Json {
+    fn from(value: f64) -> Self {
+        Json::Number(value)
+    }
+}
+
+impl From<u128> for Json {
+    fn from(value: u128) -> Self {
+        Json::Int(value)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(value: Vec<T>) -> Self {
+        Json::Array(value.into_iter().map(Into::into).collect())
+    }
+}
+
+fn write_escaped(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(b) => write!(f, "{b}"),
+            Json::Int(n) => write!(f, "{n}"),
+            Json::Number(n) if n.is_finite() => write!(f, "{n}"),
+            Json::Number(_) => f.write_str("null"),
+            Json::String(s) => write_escaped(f, s),
+            Json::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_char(']')
+            }
+            Json::Object(fields) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_escaped(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Json;
+
+    #[test]
+    fn serializes_nested_values() {
+        let value = Json::object()
+            .with("day", 1_u8)
+            .with("answer", "a \"quoted\"\nvalue")
+            .with("missing", None::<String>)
+            .with("parts", vec![true, false]);
+
+        assert_eq!(
+            value.to_string(),
+            r#"{"day":1,"answer":"a \"quoted\"\nvalue","missing":null,"parts":[true,false]}"#
+        );
+    }
+
+    #[test]
+    fn parses_serialized_values() {
+        let value = Json::object()
+            .with("answer", "tab\t \"quote\" \\ \u{1} ünïcödé")
+            .with("nanos", 1234_u128)
+            .with("error", None::<String>)
+            .with("ok", true)
+            .with("list", vec![1.5, -2.0]);
+
+        assert_eq!(Json::parse(&value.to_string()), Ok(value));
+    }
+
+    #[test]
+    fn parses_whitespace_and_escapes() {
+        let value = Json::parse(r#" { "a" : [ 1e3 , "\u00e9\ud83c\udf84" ] } "#).unwrap();
+        let list = value.get("a").and_then(Json::as_array).unwrap();
+        assert_eq!(list[0].as_f64(), Some(1000.0));
+        assert_eq!(list[1].as_str(), Some("é🎄"));
+    }
+
+    #[test]
+    fn keeps_integers_exact() {
+        let nanos = (1_u128 << 53) + 1;
+        let value = Json::object().with("nanos", nanos).with("max", u128::MAX);
+        let parsed = Json::parse(&value.to_string()).unwrap();
+
+        assert_eq!(parsed.get("nanos").and_then(Json::as_u128), Some(nanos));
+        assert_eq!(parsed.get("max").and_then(Json::as_u128), Some(u128::MAX));
+        assert_eq!(Json::parse("1e3").unwrap().as_u128(), Some(1000));
+        assert_eq!(Json::parse("-1").unwrap().as_u128(), None);
+        assert_eq!(Json::parse("1.5").unwrap().as_u128(), None);
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        assert!(Json::parse("{\"a\":}").is_err());
+        assert!(Json::parse("[1,2").is_err());
+        assert!(Json::parse("\"unterminated").is_err());
+        assert!(Json::parse("{} trailing").is_err());
+    }
+}
@@ -0,0 +1,179 @@
+/// Counting global allocator behind the `--mem` flag.
+/// The allocator is only installed with the `mem-profile` feature, which the `solve` and `all` commands enable
+/// when `--mem` is passed. It only counts while [`measure`] runs, so timings are not affected.
+use std::env;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+use std::sync::Once;
+
+/// Cargo feature that installs the counting allocator.
+pub const FEATURE: &str = "mem-profile";
+
+/// Heap usage of a solution part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Memory {
+    /// Highest number of bytes allocated at the same time, not counting memory allocated before the part ran.
+    pub peak_bytes: u128,
+    /// Number of allocations, including reallocations.
+    pub allocations: u128,
+}
+
+static IS_COUNTING: AtomicBool = AtomicBool::new(false);
+// may become negative if memory allocated before counting started is freed.
+static CURRENT_BYTES: AtomicIsize = AtomicIsize::new(0);
+static PEAK_BYTES: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "mem-profile")]
+mod allocator {
+    use super::{ALLOCATIONS, CURRENT_BYTES, IS_COUNTING, PEAK_BYTES};
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::Ordering;
+
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    impl CountingAllocator {
+        #[allow(clippy::cast_possible_wrap)]
+        fn record(grow: usize, shrink: usize) {
+            if !IS_COUNTING.load(Ordering::Relaxed) {
+                return;
+            }
+
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            let delta = grow as isize - shrink as isize;
+            let current = CURRENT_BYTES.fetch_add(delta, Ordering::Relaxed) + delta;
+            PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+        }
+    }
+
+    // SAFETY: all allocations are delegated to the system allocator, only counters are updated on top.
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                Self::record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                Self::record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            if IS_COUNTING.load(Ordering::Relaxed) {
+                #[allow(clippy::cast_possible_wrap)]
+                CURRENT_BYTES.fetch_sub(layout.size() as isize, Ordering::Relaxed);
+            }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                Self::record(new_size, layout.size());
+            }
+            new_ptr
+        }
+    }
+}
+
+/// Returns `true` if `--mem` was passed and the counting allocator is installed.
+/// Without the allocator nothing could be counted, so `--mem` is ignored with a warning.
+#[must_use]
+pub fn is_profiling() -> bool {
+    static WARNING: Once = Once::new();
+
+    if !env::args().any(|x| x == "--mem") {
+        return false;
+    }
+    if !cfg!(feature = "mem-profile") {
+        WARNING.call_once(|| {
+            eprintln!("Ignoring --mem: memory is only profiled with `--features {FEATURE}`.");
+        });
+    }
+    cfg!(feature = "mem-profile")
+}
+
+/// Runs a function while counting its heap allocations.
+/// Allocations of other threads running at the same time are counted as well.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Memory) {
+    CURRENT_BYTES.store(0, Ordering::Relaxed);
+    PEAK_BYTES.store(0, Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+
+    IS_COUNTING.store(true, Ordering::Relaxed);
+    let result = func();
+    IS_COUNTING.store(false, Ordering::Relaxed);
+
+    let memory = Memory {
+        peak_bytes: u128::try_from(PEAK_BYTES.load(Ordering::Relaxed)).unwrap_or_default(),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) as u128,
+    };
+
+    (result, memory)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 MiB`.
+#[must_use]
+pub fn format_bytes(bytes: u128) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Formats the heap usage of a part, e.g. `1.5 MiB peak, 1234 allocs`.
+#[must_use]
+pub fn format_memory(memory: &Memory) -> String {
+    format!(
+        "{} peak, {} allocs",
+        format_bytes(memory.peak_bytes),
+        memory.allocations
+    )
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, measure};
+
+    #[test]
+    #[cfg(feature = "mem-profile")]
+    fn counts_allocations() {
+        let (len, memory) = measure(|| {
+            let mut v: Vec<u64> = Vec::with_capacity(1024);
+            v.push(1);
+            let s = vec![0_u8; 4096];
+            v.len() + s.len()
+        });
+
+        // tests run in parallel, so allocations of other threads may be counted as well.
+        assert_eq!(len, 4097);
+        assert!(memory.allocations >= 2);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
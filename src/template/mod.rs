@@ -1,48 +1,124 @@
-use crate::Day;
+use crate::{PuzzleId, Year};
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod answers;
+pub mod aoc_client;
+pub mod benchmarks;
 pub mod commands;
+pub mod examples;
+pub mod html;
+pub mod input;
+pub mod json;
+pub mod memory;
+pub mod protocol;
 pub mod readme_benchmarks;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod stats;
+pub mod store;
+pub mod submissions;
+pub mod templates;
+pub mod watch;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Returns the data directory of `folder` for a year, e.g. `data/2023/inputs`.
+#[must_use]
+pub fn data_dir(year: Year, folder: &str) -> String {
+    format!("data/{year}/{folder}")
+}
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let filepath = cwd
+        .join(data_dir(puzzle.year, folder))
+        .join(format!("{}.txt", puzzle.day));
+    let f = fs::read_to_string(&filepath);
+    f.unwrap_or_else(|e| panic!("could not open input file \"{}\": {e}", filepath.display()))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+        .join(data_dir(puzzle.year, folder))
+        .join(format!("{}-{part}.txt", puzzle.day));
+    let f = fs::read_to_string(&filepath);
+    f.unwrap_or_else(|e| panic!("could not open input file \"{}\": {e}", filepath.display()))
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE`, registers the solution and sets up the input and runner for each part.
+///
+/// Solutions that parse the input once for both parts pass their parse function as a third argument,
+/// e.g. `solution!(2023, 14, parse)`. The parts then take a reference to its output instead of the input.
+///
+/// Solutions may declare the time a part is expected to take at most, e.g. `solution!(2023, 20, budget = "5s")`.
+/// It is enforced on every run, `--timeout` only limits solutions that do not declare one, see [`runner::set_budget`].
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    ($year:expr, $day:expr $(, budget = $budget:literal)?) => {
+        advent_of_code::solution!(@puzzle $year, $day, [$($budget)?], |input| {
+            use advent_of_code::template::runner::*;
+            vec![
+                run_part(part_one, input, PUZZLE, 1),
+                run_part(part_two, input, PUZZLE, 2),
+            ]
+        });
+    };
+    ($year:expr, $day:expr, $parse:expr $(, budget = $budget:literal)?) => {
+        advent_of_code::solution!(@puzzle $year, $day, [$($budget)?], |input| {
+            use advent_of_code::template::runner::*;
+            let (parsed, parse_report) = run_parse($parse, input, PUZZLE);
+            let Some(parsed) = parsed else {
+                return vec![parse_report];
+            };
+            // the closures let the parts take a slice if the parsed input is a `Vec`.
+            vec![
+                parse_report,
+                run_part(|parsed| part_one(parsed), &parsed, PUZZLE, 1),
+                run_part(|parsed| part_two(parsed), &parsed, PUZZLE, 2),
+            ]
+        });
+    };
+    (@puzzle $year:expr, $day:expr, [$($budget:literal)?], $run:expr) => {
+        /// The current year.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId = advent_of_code::PuzzleId::new(YEAR, DAY);
+
+        /// Registry entry for this day, see [`advent_of_code::template::registry`].
+        pub struct Solution;
+
+        impl advent_of_code::template::registry::Solution for Solution {
+            fn puzzle(&self) -> advent_of_code::PuzzleId {
+                PUZZLE
+            }
 
+            fn run(&self, input: &str) -> Vec<advent_of_code::template::report::PartReport> {
+                let budget: Option<&str> = None $(.or(Some($budget)))?;
+                advent_of_code::template::runner::set_budget(budget);
+                let run: fn(&str) -> Vec<advent_of_code::template::report::PartReport> = $run;
+                run(input)
+            }
+        }
+
+        // unused when the solution is compiled into the `all` binary.
+        #[allow(dead_code)]
         fn main() {
-            use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            use advent_of_code::template::registry::Solution as _;
+            let input = advent_of_code::template::input::read_from_args(PUZZLE);
+            if Solution.run(&input).iter().any(|part| part.error.is_some()) {
+                std::process::exit(1);
+            }
         }
     };
 }
@@ -0,0 +1,302 @@
+/// Side channel through which solution binaries report their results to the `all` command.
+/// When [`RESULTS_FILE_ENV`] is set, the runner appends one JSON line per part to the named file,
+/// so answers never have to be recovered from the human-readable output.
+/// The `all` binary also appends a line whenever it starts a day, so a crash can be traced to the day it happened in.
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::template::json::Json;
+use crate::template::memory::Memory;
+use crate::template::report::PartReport;
+use crate::template::stats::Stats;
+use crate::Day;
+
+/// Environment variable holding the path of the results file.
+pub const RESULTS_FILE_ENV: &str = "AOC_RESULTS_FILE";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// The records read from a results file.
+#[derive(Debug, Default)]
+pub struct Results {
+    /// The days that were started, in the order they were started.
+    pub started: Vec<Day>,
+    pub parts: Vec<(Day, PartReport)>,
+}
+
+/// Serializes the result of a part to a single line.
+#[must_use]
+pub fn encode(day: Day, report: &PartReport) -> String {
+    Json::object()
+        .with("day", day.into_inner())
+        .with("part", report.part)
+        .with("answer", report.answer.clone())
+        .with("nanos", report.stats.median)
+        .with("samples", report.stats.samples)
+        .with("min", report.stats.min)
+        .with("p5", report.stats.p5)
+        .with("p95", report.stats.p95)
+        .with("stddev", report.stats.stddev)
+        .with("peak_bytes", report.memory.map(|m| m.peak_bytes))
+        .with("allocations", report.memory.map(|m| m.allocations))
+        .with("error", report.error.clone())
+        .to_string()
+}
+
+/// Serializes the start of a day to a single line.
+#[must_use]
+pub fn encode_start(day: Day) -> String {
+    Json::object()
+        .with("day", day.into_inner())
+        .with("started", true)
+        .to_string()
+}
+
+fn parse_u128(value: Option<&Json>, field: &str) -> Result<u128, Error> {
+    value
+        .and_then(Json::as_u128)
+        .ok_or_else(|| Error::Parser(format!("missing or invalid field `{field}`")))
+}
+
+fn parse_opt_string(value: Option<&Json>, field: &str) -> Result<Option<String>, Error> {
+    match value {
+        None | Some(Json::Null) => Ok(None),
+        Some(Json::String(s)) => Ok(Some(s.clone())),
+        Some(_) => Err(Error::Parser(format!("invalid field `{field}`"))),
+    }
+}
+
+/// Parses a line written by [`encode`].
+pub fn decode(line: &str) -> Result<(Day, PartReport), Error> {
+    let value = Json::parse(line).map_err(|e| Error::Parser(e.to_string()))?;
+
+    let day = parse_u128(value.get("day"), "day")?;
+    let day = u8::try_from(day)
+        .ok()
+        .and_then(Day::new)
+        .ok_or_else(|| Error::Parser(format!("invalid day `{day}`")))?;
+
+    let part = parse_u128(value.get("part"), "part")?;
+    let part = u8::try_from(part).map_err(|_| Error::Parser(format!("invalid part `{part}`")))?;
+
+    Ok((
+        day,
+        PartReport {
+            part,
+            answer: parse_opt_string(value.get("answer"), "answer")?,
+            stats: Stats {
+                samples: parse_u128(value.get("samples"), "samples")?,
+                median: parse_u128(value.get("nanos"), "nanos")?,
+                min: parse_u128(value.get("min"), "min")?,
+                p5: parse_u128(value.get("p5"), "p5")?,
+                p95: parse_u128(value.get("p95"), "p95")?,
+                stddev: parse_u128(value.get("stddev"), "stddev")?,
+            },
+            memory: match value.get("peak_bytes") {
+                None | Some(Json::Null) => None,
+                peak_bytes => Some(Memory {
+                    peak_bytes: parse_u128(peak_bytes, "peak_bytes")?,
+                    allocations: parse_u128(value.get("allocations"), "allocations")?,
+                }),
+            },
+            error: parse_opt_string(value.get("error"), "error")?,
+        },
+    ))
+}
+
+/// Parses a line written by [`encode_start`], returns `None` for other lines.
+#[must_use]
+pub fn decode_start(line: &str) -> Option<Day> {
+    let value = Json::parse(line).ok()?;
+
+    value
+        .get("started")
+        .and_then(Json::as_bool)
+        .filter(|started| *started)?;
+
+    value
+        .get("day")
+        .and_then(Json::as_u128)
+        .and_then(|day| u8::try_from(day).ok())
+        .and_then(Day::new)
+}
+
+/// Appends a line to the results file, if one was requested by the parent process.
+fn append(line: &str) {
+    let Ok(path) = std::env::var(RESULTS_FILE_ENV) else {
+        return;
+    };
+
+    let written = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{line}"));
+
+    if let Err(e) = written {
+        eprintln!("Failed to write result to \"{path}\": {e}");
+    }
+}
+
+/// Appends the result of a part to the results file, if one was requested by the parent process.
+pub fn emit(day: Day, report: &PartReport) {
+    append(&encode(day, report));
+}
+
+/// Appends the start of a day to the results file, if one was requested by the parent process.
+pub fn emit_start(day: Day) {
+    append(&encode_start(day));
+}
+
+/// Reads all records from a results file. A missing file yields no records.
+pub fn read_results(path: &Path) -> Result<Results, Error> {
+    let mut results = Results::default();
+
+    if !path.exists() {
+        return Ok(results);
+    }
+
+    for line in fs::read_to_string(path)?.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match decode_start(line) {
+            Some(day) => results.started.push(day),
+            None => results.parts.push(decode(line)?),
+        }
+    }
+
+    Ok(results)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode, decode_start, encode, encode_start};
+    use crate::day;
+    use crate::template::memory::Memory;
+    use crate::template::report::PartReport;
+    use crate::template::stats::Stats;
+    use std::time::Duration;
+
+    fn round_trip(report: PartReport) {
+        let line = encode(day!(7), &report);
+        assert!(!line.contains('\n'));
+        let (day, decoded) = decode(&line).unwrap();
+        assert_eq!(day, day!(7));
+        assert_eq!(decoded, report);
+    }
+
+    #[test]
+    fn round_trips_patterns_in_answers() {
+        round_trip(PartReport {
+            part: 1,
+            answer: Some("@ @ @ ( ) ms (2s @ 5 samples)".into()),
+            stats: Stats::single(Duration::from_secs(2)),
+            memory: None,
+            error: None,
+        });
+        round_trip(PartReport {
+            part: 2,
+            answer: Some("Part 1: \"quoted\" \\ \x1b[1m".into()),
+            stats: Stats {
+                samples: 100_000,
+                median: 74_130,
+                min: 70_000,
+                p5: 71_000,
+                p95: 80_000,
+                stddev: 1_234,
+            },
+            memory: Some(Memory {
+                peak_bytes: 1 << 20,
+                allocations: 42,
+            }),
+            error: None,
+        });
+    }
+
+    #[test]
+    fn round_trips_multiline_answers() {
+        round_trip(PartReport {
+            part: 2,
+            answer: Some("#..#\n.##.\r\n▼".into()),
+            stats: Stats::single(Duration::from_nanos(1)),
+            memory: None,
+            error: None,
+        });
+    }
+
+    #[test]
+    fn round_trips_large_numbers() {
+        let large = (1 << 53) + 1;
+        round_trip(PartReport {
+            part: 1,
+            answer: Some("1".into()),
+            stats: Stats {
+                samples: large,
+                median: large,
+                min: large - 2,
+                p5: large,
+                p95: u128::MAX,
+                stddev: large,
+            },
+            memory: Some(Memory {
+                peak_bytes: large,
+                allocations: large + 2,
+            }),
+            error: None,
+        });
+    }
+
+    #[test]
+    fn round_trips_errors() {
+        round_trip(PartReport {
+            part: 1,
+            answer: None,
+            stats: Stats::default(),
+            memory: None,
+            error: Some("No direction available".into()),
+        });
+    }
+
+    #[test]
+    fn round_trips_day_starts() {
+        assert_eq!(decode_start(&encode_start(day!(12))), Some(day!(12)));
+
+        let line = encode(
+            day!(12),
+            &PartReport {
+                part: 1,
+                answer: None,
+                stats: Stats::default(),
+                memory: None,
+                error: None,
+            },
+        );
+        assert_eq!(decode_start(&line), None);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(decode("Part 1: 42 (1ms)").is_err());
+        let stats = r#""samples":1,"min":0,"p5":0,"p95":0,"stddev":0"#;
+        assert!(decode(&format!(r#"{{"day":1,"part":1,"nanos":0,{stats}}}"#)).is_ok());
+        assert!(decode(&format!(r#"{{"day":26,"part":1,"nanos":0,{stats}}}"#)).is_err());
+        assert!(decode(&format!(r#"{{"day":1,"part":1,"nanos":-1,{stats}}}"#)).is_err());
+        assert!(decode(&format!(
+            r#"{{"day":1,"part":1,"answer":1,"nanos":0,{stats}}}"#
+        ))
+        .is_err());
+    }
+}
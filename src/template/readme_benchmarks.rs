@@ -1,10 +1,15 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+/// Every year gets its own table, delimited by markers that include the year.
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::memory::{format_bytes, Memory};
+use crate::template::stats::{format_nanos, Stats};
+use crate::{Day, PuzzleId, Year};
 
+/// Marker of the single table of readmes that were set up before tables were kept per year.
 static MARKER: &str = "<!--- benchmarking table --->";
+static MARKER_PREFIX: &str = "<!--- benchmarking table ";
 
 #[derive(Debug)]
 pub enum Error {
@@ -21,8 +26,13 @@ impl From<std::io::Error> for Error {
 #[derive(Clone)]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// Time of the shared parse step, only present for solutions that parse the input once for both parts.
+    pub parse: Option<Stats>,
+    pub part_1: Option<Stats>,
+    pub part_2: Option<Stats>,
+    /// Heap usage of the parts, only present if the run was profiled with `--mem`.
+    pub memory_1: Option<Memory>,
+    pub memory_2: Option<Memory>,
     pub total_nanos: f64,
 }
 
@@ -32,12 +42,41 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{puzzle}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+#[must_use]
+pub fn get_marker(year: Year) -> String {
+    format!("{MARKER_PREFIX}{year} --->")
+}
+
+/// Locates the table of a year. A readme without one gets it in place of a table without a year if present,
+/// or after the last table of another year.
+fn locate_table(readme: &str, year: Year) -> Result<TablePosition, Error> {
+    let marker = get_marker(year);
+
+    if readme.contains(&marker) {
+        return locate_markers(readme, &marker);
+    }
+
+    if readme.contains(MARKER) {
+        return locate_markers(readme, MARKER);
+    }
+
+    let pos = readme
+        .rfind(MARKER_PREFIX)
+        .and_then(|start| readme[start..].find("--->").map(|end| start + end + 4))
+        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+
+    Ok(TablePosition {
+        pos_start: pos,
+        pos_end: pos,
+    })
+}
+
+fn locate_markers(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,73 +97,162 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// Formats the statistics of a part as its median, followed by the p5 - p95 range and standard deviation.
+fn format_cell(stats: Option<Stats>) -> String {
+    match stats {
+        None => "`-`".into(),
+        Some(stats) if stats.samples <= 1 => format!("`{}`", format_nanos(stats.median)),
+        Some(stats) => format!(
+            "`{}` <sub>{} – {}, σ {}</sub>",
+            format_nanos(stats.median),
+            format_nanos(stats.p5),
+            format_nanos(stats.p95),
+            format_nanos(stats.stddev)
+        ),
+    }
+}
+
+/// Formats the peak heap usage and allocation count of both parts.
+fn format_memory_cell(timing: &Timings) -> String {
+    [timing.memory_1, timing.memory_2]
+        .map(|memory| {
+            memory.map_or_else(
+                || "`-`".into(),
+                |m| {
+                    format!(
+                        "`{}` <sub>{} allocs</sub>",
+                        format_bytes(m.peak_bytes),
+                        m.allocations
+                    )
+                },
+            )
+        })
+        .join(" / ")
+}
+
+fn construct_table(prefix: &str, year: Year, timings: Vec<Timings>, total_millis: f64) -> String {
+    let marker = get_marker(year);
+    let header = format!("{prefix} {year} Benchmarks");
+    let has_memory = timings
+        .iter()
+        .any(|t| t.memory_1.is_some() || t.memory_2.is_some());
+    let has_parse = timings.iter().any(|t| t.parse.is_some());
+
+    let mut lines: Vec<String> = vec![marker.clone(), header, String::new()];
+
+    let mut columns = "| Day |".to_string();
+    let mut alignment = "| :---: |".to_string();
+    if has_parse {
+        columns.push_str(" Parse |");
+        alignment.push_str(" :---: |");
+    }
+    columns.push_str(" Part 1 | Part 2 |");
+    alignment.push_str(" :---: | :---:  |");
+    if has_memory {
+        columns.push_str(" Memory (Part 1 / Part 2) |");
+        alignment.push_str(" :---: |");
+    }
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    lines.push(columns);
+    lines.push(alignment);
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+        let path = get_path_for_bin(PuzzleId::new(year, timing.day));
+        let mut line = format!("| [Day {}]({}) |", timing.day.into_inner(), path);
+        if has_parse {
+            line.push_str(&format!(" {} |", format_cell(timing.parse)));
+        }
+        line.push_str(&format!(
+            " {} | {} |",
+            format_cell(timing.part_1),
+            format_cell(timing.part_2)
         ));
+        if has_memory {
+            line.push_str(&format!(" {} |", format_memory_cell(&timing)));
+        }
+        lines.push(line);
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(String::new());
+    lines.push(
+        "_Median of warm-up-excluded samples, with p5 – p95 range and standard deviation._".into(),
+    );
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let positions = locate_table(s, year)?;
+    let mut table = construct_table("##", year, timings, total_millis);
+    if positions.pos_start == positions.pos_end {
+        table.insert_str(0, "\n\n");
+    }
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+pub fn update(year: Year, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{get_marker, update_content, Timings, MARKER};
+    use crate::template::memory::Memory;
+    use crate::template::stats::Stats;
+    use crate::{day, year};
+    use std::time::Duration;
+
+    fn millis(median: u64) -> Option<Stats> {
+        Some(Stats {
+            samples: 100,
+            median: u128::from(median) * 1_000_000,
+            min: u128::from(median - 2) * 1_000_000,
+            p5: u128::from(median - 1) * 1_000_000,
+            p95: u128::from(median + 1) * 1_000_000,
+            stddev: 500_000,
+        })
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
+                parse: None,
+                part_1: millis(10),
+                part_2: millis(20),
+                memory_1: None,
+                memory_2: None,
                 total_nanos: 3e+10,
             },
             Timings {
                 day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
+                parse: None,
+                part_1: millis(30),
+                part_2: Some(Stats::single(Duration::from_millis(40))),
+                memory_1: None,
+                memory_2: None,
                 total_nanos: 7e+10,
             },
             Timings {
                 day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
+                parse: None,
+                part_1: millis(40),
+                part_2: None,
+                memory_1: None,
+                memory_2: None,
                 total_nanos: 9e+10,
             },
         ]
@@ -134,53 +262,102 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.contains("## 2023 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches(&get_marker(year!(2023))).count(), 2);
+        assert_eq!(
+            s.matches("## 2023 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
     }
 
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2023 --->",
+            "## 2023 Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023-01.rs) | `10.0ms` <sub>9.0ms – 11.0ms, σ 500.0µs</sub> | `20.0ms` <sub>19.0ms – 21.0ms, σ 500.0µs</sub> |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30.0ms` <sub>29.0ms – 31.0ms, σ 500.0µs</sub> | `40.0ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `40.0ms` <sub>39.0ms – 41.0ms, σ 500.0µs</sub> | `-` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "",
+            "_Median of warm-up-excluded samples, with p5 – p95 range and standard deviation._",
+            "<!--- benchmarking table 2023 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn keeps_a_table_per_year() {
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2022), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+
+        assert_eq!(s.matches(MARKER).count(), 0);
+        assert_eq!(s.matches(&get_marker(year!(2023))).count(), 2);
+        assert_eq!(s.matches(&get_marker(year!(2022))).count(), 2);
+        assert!(s.contains("| [Day 1](./src/bin/2022-01.rs) |"));
+        assert!(s.find("## 2023 Benchmarks") < s.find("## 2022 Benchmarks"));
+        assert!(s.ends_with("<!--- benchmarking table 2022 --->\nbaz"));
+    }
+
+    #[test]
+    fn format_memory_column() {
+        let mut timings = get_mock_timings();
+        timings[0].memory_1 = Some(Memory {
+            peak_bytes: 3 * 1024 * 1024,
+            allocations: 1200,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2023), timings, 190.0).unwrap();
+
+        assert!(s.contains("| Day | Part 1 | Part 2 | Memory (Part 1 / Part 2) |"));
+        assert!(s.contains("| `3.0 MiB` <sub>1200 allocs</sub> / `-` |"));
+        assert!(s.contains("| `-` / `-` |"));
+    }
+
+    #[test]
+    fn format_parse_column() {
+        let mut timings = get_mock_timings();
+        timings[1].parse = millis(5);
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2023), timings, 190.0).unwrap();
+
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |\n| :---: | :---: | :---: | :---:  |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `-` | `10.0ms`"));
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `5.0ms` <sub>4.0ms – 6.0ms, σ 500.0µs</sub> | `30.0ms`"));
+    }
 }
@@ -0,0 +1,24 @@
+/// Registry of scaffolded solutions.
+/// Every `solution!` invocation defines a `Solution` type implementing [`Solution`].
+/// The build script collects these into a static table that the `all` binary runs in a single process.
+use crate::template::report::PartReport;
+use crate::PuzzleId;
+
+/// A solution for one day of advent.
+pub trait Solution: Sync {
+    /// The puzzle this solution belongs to.
+    fn puzzle(&self) -> PuzzleId;
+
+    /// Runs all parts against `input`, printing and reporting results the same way a standalone solution binary does.
+    /// The report of the shared parse step comes first for solutions that have one.
+    fn run(&self, input: &str) -> Vec<PartReport>;
+}
+
+/// Looks up the solution for a puzzle in a registry table.
+#[must_use]
+pub fn find(
+    solutions: &[&'static dyn Solution],
+    puzzle: PuzzleId,
+) -> Option<&'static dyn Solution> {
+    solutions.iter().find(|s| s.puzzle() == puzzle).copied()
+}
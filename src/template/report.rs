@@ -0,0 +1,341 @@
+/// Module that renders the results of `cargo all` in machine-readable formats.
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::json::Json;
+use crate::template::memory::Memory;
+use crate::template::stats::Stats;
+use crate::Day;
+
+/// Output format of the `all` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = FormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(FormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Format`].
+#[derive(Debug)]
+pub struct FormatFromStrError;
+
+impl Error for FormatFromStrError {}
+
+impl Display for FormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `text`, `json` or `csv`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Part number under which the shared parse step of a solution is reported, see [`DayReport::parse`].
+pub const PARSE_PART: u8 = 0;
+
+/// The outcome of running one part of a solution.
+/// `error` holds the panic message if the part did not complete, `memory` is only measured with `--mem`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub stats: Stats,
+    pub memory: Option<Memory>,
+    pub error: Option<String>,
+}
+
+impl PartReport {
+    /// Whether the part produced an answer. The parse step has no answer and is solved if it did not panic.
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        if self.part == PARSE_PART {
+            self.error.is_none()
+        } else {
+            self.answer.is_some()
+        }
+    }
+}
+
+/// Returns the name of a part for messages, e.g. `Part 1` or `Parse` for the shared parse step.
+#[must_use]
+pub fn part_label(part: u8) -> String {
+    match part {
+        PARSE_PART => "Parse".into(),
+        part => format!("Part {part}"),
+    }
+}
+
+/// The outcome of running all parts of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayReport {
+    pub day: Day,
+    /// Whether a solution of the day was run. Days that have not been scaffolded yet are not run and have no parts.
+    /// Days that ran may have no parts as well, e.g. if their shared parse step panicked.
+    pub ran: bool,
+    /// The shared parse step, only present for solutions that parse the input once for both parts.
+    pub parse: Option<PartReport>,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        self.ran
+            && !self.parts.is_empty()
+            && self
+                .parse
+                .iter()
+                .chain(&self.parts)
+                .all(PartReport::is_solved)
+    }
+
+    /// Returns the parts that did not complete, starting with the parse step.
+    pub fn failures(&self) -> impl Iterator<Item = &PartReport> {
+        self.parse
+            .iter()
+            .chain(&self.parts)
+            .filter(|p| p.error.is_some())
+    }
+}
+
+fn part_to_json(part: &PartReport) -> Json {
+    Json::object()
+        .with("part", part.part)
+        .with("solved", part.is_solved())
+        .with("answer", part.answer.clone())
+        .with("nanos", part.is_solved().then_some(part.stats.median))
+        .with("samples", part.is_solved().then_some(part.stats.samples))
+        .with("min", part.is_solved().then_some(part.stats.min))
+        .with("p5", part.is_solved().then_some(part.stats.p5))
+        .with("p95", part.is_solved().then_some(part.stats.p95))
+        .with("stddev", part.is_solved().then_some(part.stats.stddev))
+        .with("peak_bytes", part.memory.map(|m| m.peak_bytes))
+        .with("allocations", part.memory.map(|m| m.allocations))
+        .with("error", part.error.clone())
+}
+
+fn day_to_json(report: &DayReport) -> Json {
+    let json = Json::object()
+        .with("day", report.day.into_inner())
+        .with("solved", report.is_solved());
+
+    let json = match &report.parse {
+        Some(parse) => json.with("parse", part_to_json(parse)),
+        None => json,
+    };
+
+    json.with(
+        "parts",
+        Json::Array(report.parts.iter().map(part_to_json).collect()),
+    )
+}
+
+#[must_use]
+pub fn to_json(reports: &[DayReport]) -> String {
+    let total_nanos = reports
+        .iter()
+        .flat_map(|r| r.parse.iter().chain(&r.parts))
+        .filter(|p| p.is_solved())
+        .map(|p| p.stats.median)
+        .sum::<u128>();
+
+    Json::object()
+        .with(
+            "days",
+            Json::Array(reports.iter().map(day_to_json).collect()),
+        )
+        .with("total_nanos", total_nanos)
+        .to_string()
+}
+
+fn escape_csv(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[must_use]
+pub fn to_csv(reports: &[DayReport]) -> String {
+    let mut lines = vec![
+        "day,part,solved,answer,nanos,samples,min,p5,p95,stddev,peak_bytes,allocations,error"
+            .to_string(),
+    ];
+
+    for report in reports {
+        if !report.ran {
+            lines.push(format!("{},,false,,,,,,,,,,", report.day.into_inner()));
+        }
+
+        for part in report.parse.iter().chain(&report.parts) {
+            let stats = if part.is_solved() {
+                let s = &part.stats;
+                [s.median, s.samples, s.min, s.p5, s.p95, s.stddev].map(|n| n.to_string())
+            } else {
+                Default::default()
+            };
+
+            let memory = part.memory.map_or_else(Default::default, |m| {
+                [m.peak_bytes, m.allocations].map(|n| n.to_string())
+            });
+
+            lines.push(format!(
+                "{},{},{},{},{},{},{}",
+                report.day.into_inner(),
+                part.part,
+                part.is_solved(),
+                escape_csv(part.answer.as_deref().unwrap_or_default()),
+                stats.join(","),
+                memory.join(","),
+                escape_csv(part.error.as_deref().unwrap_or_default()),
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{part_label, to_csv, to_json, DayReport, PartReport};
+    use crate::day;
+    use crate::template::memory::Memory;
+    use crate::template::stats::Stats;
+
+    fn get_mock_reports() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                day: day!(1),
+                ran: true,
+                parse: None,
+                parts: vec![
+                    PartReport {
+                        part: 1,
+                        answer: Some("42".into()),
+                        stats: Stats {
+                            samples: 10,
+                            median: 1200,
+                            min: 1000,
+                            p5: 1100,
+                            p95: 1300,
+                            stddev: 50,
+                        },
+                        memory: Some(Memory {
+                            peak_bytes: 2048,
+                            allocations: 3,
+                        }),
+                        error: None,
+                    },
+                    PartReport {
+                        part: 2,
+                        answer: None,
+                        stats: Stats::default(),
+                        memory: None,
+                        error: Some("panicked".into()),
+                    },
+                ],
+            },
+            DayReport {
+                day: day!(2),
+                ran: false,
+                parse: None,
+                parts: vec![],
+            },
+        ]
+    }
+
+    #[test]
+    fn lists_failures() {
+        let mut reports = get_mock_reports();
+        let failures: Vec<u8> = reports[0].failures().map(|p| p.part).collect();
+        assert_eq!(failures, [2]);
+        assert_eq!(reports[1].failures().count(), 0);
+
+        reports[0].parse = Some(PartReport {
+            part: 0,
+            error: Some("parse panicked".into()),
+            ..reports[0].parts[1].clone()
+        });
+        let failures: Vec<String> = reports[0].failures().map(|p| part_label(p.part)).collect();
+        assert_eq!(failures, ["Parse", "Part 2"]);
+    }
+
+    #[test]
+    fn formats_json() {
+        assert_eq!(
+            to_json(&get_mock_reports()),
+            [
+                r#"{"days":["#,
+                r#"{"day":1,"solved":false,"parts":["#,
+                r#"{"part":1,"solved":true,"answer":"42","nanos":1200,"samples":10,"#,
+                r#""min":1000,"p5":1100,"p95":1300,"stddev":50,"#,
+                r#""peak_bytes":2048,"allocations":3,"error":null},"#,
+                r#"{"part":2,"solved":false,"answer":null,"nanos":null,"samples":null,"#,
+                r#""min":null,"p5":null,"p95":null,"stddev":null,"#,
+                r#""peak_bytes":null,"allocations":null,"error":"panicked"}]},"#,
+                r#"{"day":2,"solved":false,"parts":[]}],"#,
+                r#""total_nanos":1200}"#
+            ]
+            .join("")
+        );
+    }
+
+    #[test]
+    fn formats_csv() {
+        let mut reports = get_mock_reports();
+        reports[0].parts[1].answer = Some("a,\"b\"".into());
+        reports[0].parts[1].error = None;
+
+        assert_eq!(
+            to_csv(&reports),
+            [
+                "day,part,solved,answer,nanos,samples,min,p5,p95,stddev,peak_bytes,allocations,error",
+                "1,1,true,42,1200,10,1000,1100,1300,50,2048,3,",
+                "1,2,true,\"a,\"\"b\"\"\",0,0,0,0,0,0,,,",
+                "2,,false,,,,,,,,,,",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn formats_csv_for_parse_failures() {
+        let reports = vec![DayReport {
+            day: day!(3),
+            ran: true,
+            parse: Some(PartReport {
+                part: 0,
+                answer: None,
+                stats: Stats::default(),
+                memory: None,
+                error: Some("invalid digit".into()),
+            }),
+            parts: vec![],
+        }];
+
+        assert_eq!(reports[0].is_solved(), false);
+        assert_eq!(
+            to_csv(&reports),
+            [
+                "day,part,solved,answer,nanos,samples,min,p5,p95,stddev,peak_bytes,allocations,error",
+                "3,0,false,,,,,,,,,,invalid digit",
+            ]
+            .join("\n")
+        );
+    }
+}
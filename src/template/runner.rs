@@ -1,67 +1,331 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
-use std::fmt::Display;
+use crate::template::aoc_client::{self, AocClient};
+use crate::template::memory::{self, Memory};
+use crate::template::report::{self, PartReport, PARSE_PART};
+use crate::template::stats::{format_nanos, Stats};
+use crate::template::submissions::{self, Submission};
+use crate::template::{answers, protocol, ANSI_ITALIC, ANSI_RESET};
+use crate::{Answer, Day, IntoAnswer, PuzzleId};
+use std::any::Any;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, Once};
+use std::thread;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Runs one part of a solution and reports its answer, timings and memory usage.
+/// A panicking part is reported as failed instead of unwinding into the caller.
+pub fn run_part<I: Clone, T: IntoAnswer>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) -> PartReport {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    report_panics(puzzle.day, part);
+    start_deadline();
+
+    let func = |input| func(input).map(IntoAnswer::into_answer);
+
+    // a panicking part is reported as failed, so that the other part still runs.
+    let timed = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_timed(func, input.clone(), |result| {
+            clear_deadline();
+            print_result(&result.clone().map(Answer::read_letters), &part_str, "");
+        })
+    }));
+
+    let (result, stats) = match timed {
+        Ok(timed) => timed,
+        Err(payload) => {
+            clear_deadline();
+            *CURRENT_PART.lock().unwrap() = None;
+            print!("\r");
+            println!("{part_str}: ✖ panicked");
+            return PartReport {
+                part,
+                answer: None,
+                stats: Stats::default(),
+                memory: None,
+                error: Some(panic_message(payload.as_ref())),
+            };
+        }
+    };
+
+    let result = result.map(Answer::read_letters);
+
+    let memory = memory::is_profiling().then(|| memory::measure(|| func(input)).1);
+
+    print_result(
+        &result,
+        &part_str,
+        &format!("{}{}", format_duration(&stats), format_mem(memory.as_ref())),
+    );
+
+    *CURRENT_PART.lock().unwrap() = None;
+
+    let report = PartReport {
+        part,
+        answer: result.as_ref().map(Answer::to_report),
+        stats,
+        memory,
+        error: None,
+    };
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    protocol::emit(puzzle.day, &report);
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if let Some(result) = &result {
+        submit_result(result, puzzle, part);
     }
+
+    report
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
-    input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+/// Runs the shared parse step of a solution, timed the same way as a part.
+/// The parsed input is returned to be passed to both parts, so that their times no longer include parsing.
+/// It may borrow from the input. A panicking parse step is reported as failed and returns `None`,
+/// the parts of the day are skipped then.
+pub fn run_parse<'a, T>(
+    func: impl Fn(&'a str) -> T,
+    input: &'a str,
+    puzzle: PuzzleId,
+) -> (Option<T>, PartReport) {
+    report_panics(puzzle.day, PARSE_PART);
+    start_deadline();
+
+    let timed = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_timed(&func, input, |_| {
+            clear_deadline();
+            print!("Parse:");
+        })
+    }));
+
+    let (parsed, stats) = match timed {
+        Ok(timed) => timed,
+        Err(payload) => {
+            clear_deadline();
+            *CURRENT_PART.lock().unwrap() = None;
+            print!("\r");
+            println!("Parse: ✖ panicked, skipping both parts");
+            return (
+                None,
+                PartReport {
+                    part: PARSE_PART,
+                    answer: None,
+                    stats: Stats::default(),
+                    memory: None,
+                    error: Some(panic_message(payload.as_ref())),
+                },
+            );
+        }
+    };
+
+    let memory = memory::is_profiling().then(|| memory::measure(|| func(input)).1);
+
+    print!("\r");
+    println!(
+        "Parse:{}{}",
+        format_duration(&stats),
+        format_mem(memory.as_ref())
+    );
+
+    *CURRENT_PART.lock().unwrap() = None;
+
+    let report = PartReport {
+        part: PARSE_PART,
+        answer: None,
+        stats,
+        memory,
+        error: None,
+    };
+
+    protocol::emit(puzzle.day, &report);
+
+    (Some(parsed), report)
+}
+
+static CURRENT_PART: Mutex<Option<(Day, u8)>> = Mutex::new(None);
+
+/// Installs a panic hook that reports the panic message of the running part through the results protocol.
+fn report_panics(day: Day, part: u8) {
+    static INSTALL_HOOK: Once = Once::new();
+
+    *CURRENT_PART.lock().unwrap() = Some((day, part));
+
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if let Some((day, part)) = CURRENT_PART.lock().ok().and_then(|p| *p) {
+                protocol::emit(
+                    day,
+                    &PartReport {
+                        part,
+                        answer: None,
+                        stats: Stats::default(),
+                        memory: None,
+                        error: Some(panic_message(info.payload())),
+                    },
+                );
+            }
+            default_hook(info);
+        }));
+    });
+}
+
+/// Returns the message a panic was raised with.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| (*s).to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".into())
+}
+
+/// Time limit of a single run of a part, set by [`set_budget`].
+static TIMEOUT: Mutex<Option<Duration>> = Mutex::new(None);
+/// Point in time at which the running part times out.
+static DEADLINE: Mutex<Option<Instant>> = Mutex::new(None);
+/// Exit code of a solution that was stopped because a part timed out, the same as the one of coreutils' `timeout`.
+pub const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Sets the time limit of the parts of the next solution, see [`get_time_limit`].
+pub fn set_budget(budget: Option<&str>) {
+    match get_time_limit(budget, get_timeout()) {
+        Ok(timeout) => *TIMEOUT.lock().unwrap() = timeout,
+        Err(e) => {
+            eprintln!("Invalid budget in `solution!`: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Returns the time limit of the parts of a solution: the budget it declares in `solution!` if any,
+/// otherwise the duration passed with `--timeout`. Parts of solutions without either run for as long as they take.
+fn get_time_limit(
+    budget: Option<&str>,
+    timeout: Option<Duration>,
+) -> Result<Option<Duration>, String> {
+    match budget {
+        Some(budget) => parse_duration(budget).map(Some),
+        None => Ok(timeout),
+    }
+}
+
+/// Reads the default time limit of parts from the `--timeout` argument.
+fn get_timeout() -> Option<Duration> {
+    let args: Vec<String> = env::args().collect();
+    let index = args.iter().position(|x| x == "--timeout")?;
+
+    match args.get(index + 1).map(|s| parse_duration(s)) {
+        Some(Ok(duration)) => Some(duration),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: --timeout 10s");
+            process::exit(1);
+        }
+    }
+}
+
+/// Starts the time limit of the running part, see [`set_budget`].
+/// A watchdog thread reports a part that exceeds it as timed out and stops the process,
+/// since a running part can not be cancelled.
+fn start_deadline() {
+    static START_WATCHDOG: Once = Once::new();
+
+    let Some(timeout) = *TIMEOUT.lock().unwrap() else {
+        return;
+    };
+
+    *DEADLINE.lock().unwrap() = Some(Instant::now() + timeout);
+
+    START_WATCHDOG.call_once(|| {
+        thread::spawn(move || loop {
+            thread::sleep(Duration::from_millis(10));
+
+            let is_expired = DEADLINE
+                .lock()
+                .unwrap()
+                .is_some_and(|deadline| Instant::now() >= deadline);
+
+            if let (true, Some((day, part))) = (is_expired, *CURRENT_PART.lock().unwrap()) {
+                let timeout = TIMEOUT.lock().unwrap().unwrap_or_default();
+                let error = format!("timed out after {}", format_nanos(timeout.as_nanos()));
+
+                print!("\r");
+                println!("{}: ✖ {error}", report::part_label(part));
+                let _ = stdout().flush();
+
+                protocol::emit(
+                    day,
+                    &PartReport {
+                        part,
+                        answer: None,
+                        stats: Stats::default(),
+                        memory: None,
+                        error: Some(error),
+                    },
+                );
+                process::exit(TIMEOUT_EXIT_CODE);
+            }
+        });
+    });
+}
+
+/// Stops the time limit once the first run of a part has finished, benchmark runs are not limited.
+fn clear_deadline() {
+    *DEADLINE.lock().unwrap() = None;
+}
+
+/// Run a solution part. The behavior differs depending on whether the `--time` flag is set:
+///  1. without it, the function is executed once.
+///  2. with it, the function is benched (see [`bench`]).
+fn run_timed<I: Clone, T>(func: impl Fn(I) -> T, input: I, hook: impl Fn(&T)) -> (T, Stats) {
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if env::args().any(|x| x == "--time") {
+        bench(func, input)
     } else {
-        (base_time, 1)
+        Stats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    (result, stats)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Default time budget for benchmarking a part, can be changed with `--bench-time`.
+const DEFAULT_BENCH_TIME: Duration = Duration::from_secs(1);
+/// Minimum number of measured samples, even if this exceeds the time budget.
+const MIN_SAMPLES: usize = 10;
+/// Maximum number of measured samples, bounds memory usage for very fast parts.
+const MAX_SAMPLES: usize = 1_000_000;
+
+/// Bench a solution part:
+///  1. the function is warmed up for a tenth of the time budget. These runs are not measured.
+///  2. the function is sampled until the time budget is used up, with at least 10 samples.
+///  3. outliers are rejected and summary statistics are computed over the remaining samples.
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I) -> Stats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let budget = get_bench_time();
+
+    let warmup = Instant::now();
+    while warmup.elapsed() < budget / 10 {
+        func(input.clone());
+    }
 
     let mut timers: Vec<Duration> = vec![];
+    let start = Instant::now();
 
-    for _ in 0..bench_iterations {
+    while timers.len() < MIN_SAMPLES || (start.elapsed() < budget && timers.len() < MAX_SAMPLES) {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
@@ -69,45 +333,89 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Reads the time budget for benchmarks from the `--bench-time` argument.
+fn get_bench_time() -> Duration {
+    let args: Vec<String> = env::args().collect();
+
+    let Some(index) = args.iter().position(|x| x == "--bench-time") else {
+        return DEFAULT_BENCH_TIME;
+    };
+
+    match args.get(index + 1).map(|s| parse_duration(s)) {
+        Some(Ok(duration)) => duration,
+        _ => {
+            eprintln!("Unexpected command-line input. Format: --bench-time 5s");
+            process::exit(1);
+        }
+    }
+}
+
+/// Parses a human-readable duration such as `500ms`, `5s` or `1.5m`.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration `{s}`"))?;
+
+    let seconds = match unit {
+        "ns" => value / 1e9,
+        "us" | "µs" => value / 1e6,
+        "ms" => value / 1e3,
+        "s" | "" => value,
+        "m" => value * 60.0,
+        _ => return Err(format!("invalid duration unit `{unit}` in `{s}`")),
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("invalid duration `{s}`"))
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_duration(stats: &Stats) -> String {
+    let median = format_nanos(stats.median);
+
+    if stats.samples == 1 {
+        format!(" ({median})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({median} ±{}, p5 {}, p95 {} @ {} samples)",
+            format_nanos(stats.stddev),
+            format_nanos(stats.p5),
+            format_nanos(stats.p95),
+            stats.samples
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_mem(memory: Option<&Memory>) -> String {
+    memory.map_or_else(String::new, |memory| {
+        format!(" [{}]", memory::format_memory(memory))
+    })
+}
+
+fn print_result(result: &Option<Answer>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
-            if result.to_string().contains('\n') {
+            let pretty = result.pretty();
+            if result.is_multi_line() {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
                     print!("\r");
                     println!("{str}");
-                    println!("{result}");
+                    println!("{pretty}");
                 }
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                let str = format!("{part}: {ANSI_BOLD}{pretty}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -129,12 +437,16 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
+///  2. a session cookie is configured.
+///
+/// Answers that can not be submitted, e.g. multi-line answers, or that contradict the submission log of the day
+/// are refused without contacting the server.
+/// Every submission is logged, accepted answers are also recorded for `cargo verify`.
+fn submit_result(
+    result: &Answer,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<String, aoc_client::Error>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -157,11 +469,92 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let result = match result.to_submission() {
+        Ok(result) => result,
+        Err(refusal) => {
+            eprintln!("Refusing to submit the answer of part {part}: {refusal}");
+            process::exit(1);
+        }
+    };
+
+    let log = match submissions::read(puzzle) {
+        Ok(log) => log,
+        Err(e) => {
+            eprintln!(
+                "Failed to read \"{}\": {e:?}",
+                submissions::get_path(puzzle)
+            );
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = submissions::check(&log, part, &result, submissions::now()) {
+        eprintln!("Refusing to submit `{result}`: {refusal}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to submit result: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("Submitting result...");
+    let outcome = client.submit(puzzle, part, &result);
+
+    if let Some(submission) = Submission::from_outcome(part, &result, &outcome, submissions::now())
+    {
+        if let Err(e) = submissions::record(puzzle, &submission) {
+            eprintln!("Failed to log submission: {e:?}");
+        }
+    }
+
+    match &outcome {
+        Ok(message) => {
+            println!("{message}");
+            match answers::record(puzzle, part, &result) {
+                Ok(()) => println!("Recorded answer in \"{}\".", answers::get_path(puzzle)),
+                Err(e) => eprintln!("Failed to record answer: {e:?}"),
+            }
+        }
+        Err(e) => eprintln!("Submission failed: {e}"),
+    }
+
+    Some(outcome)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get_time_limit, parse_duration};
+    use std::time::Duration;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("5s"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("1.5m"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("20µs"), Ok(Duration::from_micros(20)));
+        assert_eq!(parse_duration("2"), Ok(Duration::from_secs(2)));
+        assert!(parse_duration("5 hours").is_err());
+        assert!(parse_duration("ms").is_err());
+    }
+
+    #[test]
+    fn applies_declared_budgets_without_timeout() {
+        let timeout = Some(Duration::from_secs(10));
+
+        assert_eq!(
+            get_time_limit(Some("5s"), None),
+            Ok(Some(Duration::from_secs(5)))
+        );
+        assert_eq!(
+            get_time_limit(Some("5s"), timeout),
+            Ok(Some(Duration::from_secs(5)))
+        );
+        assert_eq!(get_time_limit(None, timeout), Ok(timeout));
+        assert_eq!(get_time_limit(None, None), Ok(None));
+        assert!(get_time_limit(Some("soon"), None).is_err());
+    }
 }
@@ -0,0 +1,127 @@
+/// Summary statistics over the samples collected while benchmarking a solution part.
+use std::time::Duration;
+
+/// Timing statistics of a part, all durations are in nanoseconds.
+/// Samples outside of the Tukey fences (1.5 × IQR) are rejected as outliers before computing the statistics.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    /// Number of samples the statistics are based on, after outlier rejection.
+    pub samples: u128,
+    pub median: u128,
+    pub min: u128,
+    pub p5: u128,
+    pub p95: u128,
+    pub stddev: u128,
+}
+
+impl Stats {
+    /// Statistics for a part that was executed once.
+    #[must_use]
+    pub fn single(duration: Duration) -> Self {
+        let nanos = duration.as_nanos();
+        Self {
+            samples: 1,
+            median: nanos,
+            min: nanos,
+            p5: nanos,
+            p95: nanos,
+            stddev: 0,
+        }
+    }
+
+    /// Computes statistics over a set of samples, rejecting outliers first.
+    /// Returns [`Stats::default`] if there are no samples.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        if sorted.is_empty() {
+            return Self::default();
+        }
+
+        let q1 = percentile(&sorted, 0.25) as f64;
+        let q3 = percentile(&sorted, 0.75) as f64;
+        let iqr = q3 - q1;
+        let (lower, upper) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        let kept: Vec<u128> = sorted
+            .into_iter()
+            .filter(|&n| (lower..=upper).contains(&(n as f64)))
+            .collect();
+
+        let count = kept.len() as f64;
+        let mean = kept.iter().sum::<u128>() as f64 / count;
+        let variance =
+            kept.iter().map(|&n| (n as f64 - mean).powi(2)).sum::<f64>() / (count - 1.0).max(1.0);
+
+        Self {
+            samples: kept.len() as u128,
+            median: percentile(&kept, 0.5),
+            min: kept[0],
+            p5: percentile(&kept, 0.05),
+            p95: percentile(&kept, 0.95),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            stddev: variance.sqrt().round() as u128,
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let idx = (p * (sorted.len() - 1) as f64).round() as usize;
+    sorted[idx]
+}
+
+/// Formats a duration in nanoseconds the same way solutions display timings, e.g. `1.2ms`.
+#[must_use]
+pub fn format_nanos(nanos: u128) -> String {
+    let nanos = u64::try_from(nanos).unwrap_or(u64::MAX);
+    format!("{:.1?}", Duration::from_nanos(nanos))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_nanos, Stats};
+    use std::time::Duration;
+
+    fn samples(nanos: &[u64]) -> Vec<Duration> {
+        nanos.iter().map(|n| Duration::from_nanos(*n)).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = Stats::from_samples(&samples(&[10, 12, 11, 13, 14, 10, 12, 11, 13, 14]));
+        assert_eq!(stats.samples, 10);
+        assert_eq!(stats.median, 12);
+        assert_eq!(stats.min, 10);
+        assert_eq!(stats.p5, 10);
+        assert_eq!(stats.p95, 14);
+        assert_eq!(stats.stddev, 1);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&samples(&[100, 101, 99, 100, 102, 99, 101, 100, 5000]));
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.median, 100);
+        assert_eq!(stats.p95, 102);
+    }
+
+    #[test]
+    fn handles_single_and_missing_samples() {
+        assert_eq!(Stats::from_samples(&[]), Stats::default());
+        assert_eq!(
+            Stats::from_samples(&samples(&[42])),
+            Stats::single(Duration::from_nanos(42))
+        );
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_nanos(74), "74.0ns");
+        assert_eq!(format_nanos(1_500_000), "1.5ms");
+        assert_eq!(format_nanos(2_000_000_000), "2.0s");
+    }
+}
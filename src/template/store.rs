@@ -0,0 +1,199 @@
+/// Local store of downloaded puzzle inputs and descriptions.
+/// Files that are already present with content are never fetched again, unless a refetch is forced.
+/// Every fetch is recorded in `data/<year>/meta/<day>.json` so cached puzzles can be read offline.
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+
+use crate::template::data_dir;
+use crate::template::json::Json;
+use crate::{Day, PuzzleId, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// Metadata of the last fetch of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Meta {
+    pub puzzle: PuzzleId,
+    /// Unix timestamp of the fetch.
+    pub fetched_at: u64,
+}
+
+#[must_use]
+pub fn get_input_path(puzzle: PuzzleId) -> String {
+    format!("{}/{}.txt", data_dir(puzzle.year, "inputs"), puzzle.day)
+}
+
+#[must_use]
+pub fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    format!("{}/{}.md", data_dir(puzzle.year, "puzzles"), puzzle.day)
+}
+
+#[must_use]
+pub fn get_meta_path(puzzle: PuzzleId) -> String {
+    format!("{}/{}.json", data_dir(puzzle.year, "meta"), puzzle.day)
+}
+
+/// Whether a file exists and has content. Scaffolding creates empty input files, these do not count as cached.
+fn is_cached(path: &str) -> bool {
+    fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty())
+}
+
+#[must_use]
+pub fn has_input(puzzle: PuzzleId) -> bool {
+    is_cached(&get_input_path(puzzle))
+}
+
+#[must_use]
+pub fn has_puzzle(puzzle: PuzzleId) -> bool {
+    is_cached(&get_puzzle_path(puzzle))
+}
+
+fn write(path: &str, contents: &str) -> Result<(), Error> {
+    if let Some(dir) = std::path::Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+pub fn write_input(puzzle: PuzzleId, input: &str) -> Result<(), Error> {
+    write(&get_input_path(puzzle), input)
+}
+
+pub fn write_puzzle(puzzle: PuzzleId, description: &str) -> Result<(), Error> {
+    write(&get_puzzle_path(puzzle), description)
+}
+
+/// Reads the cached description of a puzzle, if present.
+#[must_use]
+pub fn read_puzzle(puzzle: PuzzleId) -> Option<String> {
+    fs::read_to_string(get_puzzle_path(puzzle))
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+}
+
+#[must_use]
+pub fn serialize_meta(meta: &Meta) -> String {
+    Json::object()
+        .with("year", u128::from(meta.puzzle.year.into_inner()))
+        .with("day", meta.puzzle.day.into_inner())
+        .with("fetched_at", u128::from(meta.fetched_at))
+        .to_string()
+}
+
+pub fn parse_meta(s: &str) -> Result<Meta, Error> {
+    let value = Json::parse(s).map_err(|e| Error::Parser(e.to_string()))?;
+
+    let number = |field: &str| {
+        value
+            .get(field)
+            .and_then(Json::as_f64)
+            .filter(|n| *n >= 0.0 && n.fract() == 0.0)
+    };
+
+    let day = number("day")
+        .and_then(|n| u8::try_from(n as u64).ok())
+        .and_then(Day::new)
+        .ok_or_else(|| Error::Parser("missing or invalid field `day`".into()))?;
+
+    let year = number("year")
+        .and_then(|n| u16::try_from(n as u64).ok())
+        .and_then(Year::new)
+        .ok_or_else(|| Error::Parser("missing or invalid field `year`".into()))?;
+
+    let fetched_at = number("fetched_at")
+        .map(|n| n as u64)
+        .ok_or_else(|| Error::Parser("missing or invalid field `fetched_at`".into()))?;
+
+    Ok(Meta {
+        puzzle: PuzzleId::new(year, day),
+        fetched_at,
+    })
+}
+
+/// Reads the metadata of a puzzle. Puzzles that were never fetched yield `None`.
+pub fn read_meta(puzzle: PuzzleId) -> Result<Option<Meta>, Error> {
+    match fs::read_to_string(get_meta_path(puzzle)) {
+        Ok(s) => parse_meta(&s).map(Some),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Records that a puzzle was just fetched.
+pub fn record_fetch(puzzle: PuzzleId) -> Result<Meta, Error> {
+    let meta = Meta {
+        puzzle,
+        fetched_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+    };
+
+    fs::create_dir_all(data_dir(puzzle.year, "meta"))?;
+    fs::write(get_meta_path(puzzle), serialize_meta(&meta))?;
+    Ok(meta)
+}
+
+/// Formats a unix timestamp as a UTC date, e.g. `2023-12-01 05:00 UTC`.
+#[must_use]
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // civil date from days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02} UTC",
+        seconds / 3600,
+        (seconds % 3600) / 60
+    )
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_timestamp, parse_meta, serialize_meta, Meta};
+    use crate::{day, year, PuzzleId};
+
+    #[test]
+    fn round_trips_meta() {
+        let meta = Meta {
+            puzzle: PuzzleId::new(year!(2023), day!(16)),
+            fetched_at: 1_702_702_800,
+        };
+        assert_eq!(parse_meta(&serialize_meta(&meta)).unwrap(), meta);
+    }
+
+    #[test]
+    fn rejects_invalid_meta() {
+        assert!(parse_meta(r#"{"year":2023,"day":0,"fetched_at":1}"#).is_err());
+        assert!(parse_meta(r#"{"year":-1,"day":1,"fetched_at":1}"#).is_err());
+        assert!(parse_meta(r#"{"day":1,"fetched_at":1}"#).is_err());
+        assert!(parse_meta(r#"{"year":2023,"day":1}"#).is_err());
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00 UTC");
+        assert_eq!(format_timestamp(1_701_406_800), "2023-12-01 05:00 UTC");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00 UTC");
+    }
+}
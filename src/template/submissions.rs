@@ -0,0 +1,370 @@
+/// Module that keeps a log of submitted answers in `data/<year>/submissions/<day>.jsonl`.
+/// The log is used to refuse submissions that are known to be wrong before they reach the server.
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::template::aoc_client::{self, Hint};
+use crate::template::data_dir;
+use crate::template::json::Json;
+use crate::PuzzleId;
+
+/// Time to wait after a wrong answer if the response of the site does not say how long.
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was not checked because an answer was submitted too recently.
+    RateLimited,
+}
+
+impl Verdict {
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate_limited",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "correct" => Some(Verdict::Correct),
+            "too_high" => Some(Verdict::TooHigh),
+            "too_low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            "rate_limited" => Some(Verdict::RateLimited),
+            _ => None,
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+/// One submitted answer and the response of the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix timestamp of the submission.
+    pub timestamp: u64,
+    /// Seconds to wait before the next submission.
+    pub wait_secs: u64,
+}
+
+impl Submission {
+    /// Creates a log entry for the outcome of a submission made at `timestamp`.
+    /// Returns `None` for errors that say nothing about the answer, e.g. a failed request.
+    #[must_use]
+    pub fn from_outcome(
+        part: u8,
+        answer: &str,
+        outcome: &Result<String, aoc_client::Error>,
+        timestamp: u64,
+    ) -> Option<Self> {
+        let (verdict, wait) = match outcome {
+            Ok(_) => (Verdict::Correct, Duration::ZERO),
+            Err(aoc_client::Error::WrongAnswer(hint, wait)) => {
+                let verdict = match hint {
+                    Some(Hint::TooHigh) => Verdict::TooHigh,
+                    Some(Hint::TooLow) => Verdict::TooLow,
+                    None => Verdict::Wrong,
+                };
+                (verdict, wait.unwrap_or(DEFAULT_WAIT))
+            }
+            Err(aoc_client::Error::RateLimited(wait)) => {
+                (Verdict::RateLimited, wait.unwrap_or(DEFAULT_WAIT))
+            }
+            Err(_) => return None,
+        };
+
+        Some(Self {
+            part,
+            answer: answer.to_string(),
+            verdict,
+            timestamp,
+            wait_secs: wait.as_secs(),
+        })
+    }
+}
+
+/// Reason for refusing to submit an answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong(Verdict),
+    /// The answer is at least as high as an answer that was too high.
+    AboveBound(String),
+    /// The answer is at most as low as an answer that was too low.
+    BelowBound(String),
+    Wait(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part has already been solved with `{answer}`.")
+            }
+            Refusal::KnownWrong(Verdict::TooHigh) => {
+                write!(f, "this answer was already submitted and is too high.")
+            }
+            Refusal::KnownWrong(Verdict::TooLow) => {
+                write!(f, "this answer was already submitted and is too low.")
+            }
+            Refusal::KnownWrong(_) => write!(f, "this answer was already submitted and is wrong."),
+            Refusal::AboveBound(bound) => {
+                write!(
+                    f,
+                    "the answer must be lower than `{bound}`, which was too high."
+                )
+            }
+            Refusal::BelowBound(bound) => {
+                write!(
+                    f,
+                    "the answer must be higher than `{bound}`, which was too low."
+                )
+            }
+            Refusal::Wait(wait) => write!(
+                f,
+                "an answer was submitted too recently, wait {}s before submitting again.",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+/// Checks an answer against the submissions of a day, at unix time `now`.
+pub fn check(log: &[Submission], part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+    let submissions = || log.iter().filter(|s| s.part == part);
+
+    if let Some(correct) = submissions().find(|s| s.verdict == Verdict::Correct) {
+        return Err(Refusal::AlreadySolved(correct.answer.clone()));
+    }
+
+    if let Some(known) = submissions().find(|s| s.answer == answer && s.verdict.is_wrong()) {
+        return Err(Refusal::KnownWrong(known.verdict));
+    }
+
+    if let Ok(value) = answer.parse::<i128>() {
+        let bound = |verdict: Verdict| {
+            submissions()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        if let Some(high) = bound(Verdict::TooHigh).filter(|high| value >= *high).min() {
+            return Err(Refusal::AboveBound(high.to_string()));
+        }
+
+        if let Some(low) = bound(Verdict::TooLow).filter(|low| value <= *low).max() {
+            return Err(Refusal::BelowBound(low.to_string()));
+        }
+    }
+
+    // the wait period applies to both parts.
+    if let Some(wait_until) = log.iter().map(|s| s.timestamp + s.wait_secs).max() {
+        if wait_until > now {
+            return Err(Refusal::Wait(Duration::from_secs(wait_until - now)));
+        }
+    }
+
+    Ok(())
+}
+
+#[must_use]
+pub fn get_path(puzzle: PuzzleId) -> String {
+    format!(
+        "{}/{}.jsonl",
+        data_dir(puzzle.year, "submissions"),
+        puzzle.day
+    )
+}
+
+#[must_use]
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[must_use]
+pub fn encode(submission: &Submission) -> String {
+    Json::object()
+        .with("part", submission.part)
+        .with("answer", submission.answer.as_str())
+        .with("verdict", submission.verdict.as_str())
+        .with("timestamp", u128::from(submission.timestamp))
+        .with("wait_secs", u128::from(submission.wait_secs))
+        .to_string()
+}
+
+pub fn decode(line: &str) -> Result<Submission, Error> {
+    let value = Json::parse(line).map_err(|e| Error::Parser(e.to_string()))?;
+
+    let number = |field: &str| {
+        value
+            .get(field)
+            .and_then(Json::as_f64)
+            .filter(|n| *n >= 0.0 && n.fract() == 0.0)
+            .map(|n| n as u64)
+            .ok_or_else(|| Error::Parser(format!("missing or invalid field `{field}`")))
+    };
+
+    let string = |field: &str| {
+        value
+            .get(field)
+            .and_then(Json::as_str)
+            .ok_or_else(|| Error::Parser(format!("missing or invalid field `{field}`")))
+    };
+
+    Ok(Submission {
+        part: u8::try_from(number("part")?)
+            .map_err(|_| Error::Parser("invalid field `part`".into()))?,
+        answer: string("answer")?.to_string(),
+        verdict: Verdict::parse(string("verdict")?)
+            .ok_or_else(|| Error::Parser("invalid field `verdict`".into()))?,
+        timestamp: number("timestamp")?,
+        wait_secs: number("wait_secs")?,
+    })
+}
+
+/// Reads the submissions of a day. A missing log yields no submissions.
+pub fn read(puzzle: PuzzleId) -> Result<Vec<Submission>, Error> {
+    match fs::read_to_string(get_path(puzzle)) {
+        Ok(s) => s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(decode)
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Appends a submission to the log of a day.
+pub fn record(puzzle: PuzzleId, submission: &Submission) -> Result<(), Error> {
+    fs::create_dir_all(data_dir(puzzle.year, "submissions"))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_path(puzzle))?;
+    writeln!(file, "{}", encode(submission))?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, decode, encode, Refusal, Submission, Verdict};
+    use crate::template::aoc_client::{Error, Hint};
+    use std::time::Duration;
+
+    fn submission(part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            part,
+            answer: answer.into(),
+            verdict,
+            timestamp: 1_000,
+            wait_secs: 60,
+        }
+    }
+
+    #[test]
+    fn round_trips_submissions() {
+        let submission = submission(2, "#..#\n\"x\"", Verdict::TooLow);
+        assert_eq!(decode(&encode(&submission)).unwrap(), submission);
+        assert!(
+            decode(r#"{"part":1,"answer":"1","verdict":"maybe","timestamp":1,"wait_secs":0}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let log = [
+            submission(1, "100", Verdict::TooHigh),
+            submission(1, "10", Verdict::TooLow),
+            submission(1, "abc", Verdict::Wrong),
+        ];
+
+        assert_eq!(
+            check(&log, 1, "abc", 2_000),
+            Err(Refusal::KnownWrong(Verdict::Wrong))
+        );
+        assert_eq!(
+            check(&log, 1, "100", 2_000),
+            Err(Refusal::KnownWrong(Verdict::TooHigh))
+        );
+        assert_eq!(
+            check(&log, 1, "150", 2_000),
+            Err(Refusal::AboveBound("100".into()))
+        );
+        assert_eq!(
+            check(&log, 1, "-5", 2_000),
+            Err(Refusal::BelowBound("10".into()))
+        );
+        assert_eq!(check(&log, 1, "50", 2_000), Ok(()));
+        assert_eq!(check(&log, 2, "150", 2_000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let log = [submission(1, "42", Verdict::Correct)];
+        assert_eq!(
+            check(&log, 1, "43", 2_000),
+            Err(Refusal::AlreadySolved("42".into()))
+        );
+    }
+
+    #[test]
+    fn enforces_wait_period() {
+        let log = [submission(1, "100", Verdict::TooHigh)];
+        assert_eq!(
+            check(&log, 2, "1", 1_030),
+            Err(Refusal::Wait(Duration::from_secs(30)))
+        );
+        assert_eq!(check(&log, 2, "1", 1_060), Ok(()));
+    }
+
+    #[test]
+    fn records_outcomes() {
+        let outcome = Err(Error::RateLimited(Some(Duration::from_secs(65))));
+        let entry = Submission::from_outcome(1, "7", &outcome, 5).unwrap();
+        assert_eq!(entry.verdict, Verdict::RateLimited);
+        assert_eq!(entry.wait_secs, 65);
+
+        let outcome = Err(Error::WrongAnswer(Some(Hint::TooLow), None));
+        let entry = Submission::from_outcome(1, "7", &outcome, 5).unwrap();
+        assert_eq!(entry.verdict, Verdict::TooLow);
+        assert_eq!(entry.wait_secs, 60);
+
+        let outcome = Err(Error::WrongAnswer(None, Some(Duration::from_secs(300))));
+        let entry = Submission::from_outcome(1, "7", &outcome, 5).unwrap();
+        assert_eq!(entry.verdict, Verdict::Wrong);
+        assert_eq!(entry.wait_secs, 300);
+
+        assert!(Submission::from_outcome(1, "7", &Err(Error::Auth), 5).is_none());
+    }
+}
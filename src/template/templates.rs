@@ -0,0 +1,190 @@
+/// Module that renders the module files created by `cargo scaffold`.
+/// Templates are read from `templates/<name>.rs.tmpl` and may use the placeholders
+/// `{{year}}`, `{{day}}`, `{{day_padded}}`, `{{title}}` and `{{answer_type}}`.
+/// A literal `{{`, e.g. in a format string, is written as `\{{`.
+use std::{fs, io};
+
+use crate::PuzzleId;
+
+pub const TEMPLATES_DIR: &str = "templates";
+
+#[derive(Debug)]
+pub enum Error {
+    NotFound(String),
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotFound(e) | Error::Parser(e) => f.write_str(e),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Values of the placeholders of a template.
+pub struct Context {
+    pub puzzle: PuzzleId,
+    /// Title of the puzzle, e.g. `Day 1: Trebuchet?!`.
+    pub title: String,
+    /// Type of the answers returned by both parts, e.g. `u32`.
+    pub answer_type: String,
+}
+
+impl Context {
+    fn get(&self, placeholder: &str) -> Option<String> {
+        match placeholder {
+            "year" => Some(self.puzzle.year.to_string()),
+            "day" => Some(self.puzzle.day.into_inner().to_string()),
+            "day_padded" => Some(self.puzzle.day.to_string()),
+            "title" => Some(self.title.clone()),
+            "answer_type" => Some(self.answer_type.clone()),
+            _ => None,
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(name: &str) -> String {
+    format!("{TEMPLATES_DIR}/{name}.rs.tmpl")
+}
+
+/// Lists the names of the templates in the templates directory.
+#[must_use]
+pub fn list() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_suffix(".rs.tmpl").map(ToString::to_string)
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Reads a template by name.
+pub fn load(name: &str) -> Result<String, Error> {
+    match fs::read_to_string(get_path(name)) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let available = list();
+            Err(Error::NotFound(if available.is_empty() {
+                format!(
+                    "template \"{}\" does not exist, no templates found in \"{TEMPLATES_DIR}\".",
+                    get_path(name)
+                )
+            } else {
+                format!(
+                    "template \"{}\" does not exist, available templates: {}.",
+                    get_path(name),
+                    available.join(", ")
+                )
+            }))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Replaces every `{{placeholder}}` of a template. Unknown placeholders are an error.
+/// An escaped `\{{` is rendered as `{{`.
+pub fn render(template: &str, context: &Context) -> Result<String, Error> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        if let Some(before) = rest[..start].strip_suffix('\\') {
+            rendered.push_str(before);
+            rendered.push_str("{{");
+            rest = &rest[start + 2..];
+            continue;
+        }
+
+        rendered.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| Error::Parser("unclosed placeholder `{{`".into()))?;
+
+        let placeholder = rest[start + 2..start + end].trim();
+        let value = context
+            .get(placeholder)
+            .ok_or_else(|| Error::Parser(format!("unknown placeholder `{{{{{placeholder}}}}}`")))?;
+
+        rendered.push_str(&value);
+        rest = &rest[start + end + 2..];
+    }
+
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+/// Reads the title from the heading of a puzzle description, e.g. `Day 1: Trebuchet?!`
+/// for `## --- Day 1: Trebuchet?! ---`.
+#[must_use]
+pub fn get_title(description: &str) -> Option<String> {
+    description
+        .lines()
+        .find_map(|line| line.strip_prefix("## --- Day "))
+        .map(|title| format!("Day {}", title.trim_end_matches('-').trim()))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get_title, render, Context};
+    use crate::{day, year, PuzzleId};
+
+    fn context() -> Context {
+        Context {
+            puzzle: PuzzleId::new(year!(2023), day!(7)),
+            title: "Day 7: Camel Cards".into(),
+            answer_type: "u64".into(),
+        }
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let template = "//! {{title}}\nsolution!({{ year }}, {{day}}); // {{day_padded}}\nfn f() -> Option<{{answer_type}}> {}";
+        assert_eq!(
+            render(template, &context()).unwrap(),
+            "//! Day 7: Camel Cards\nsolution!(2023, 7); // 07\nfn f() -> Option<u64> {}"
+        );
+    }
+
+    #[test]
+    fn renders_escaped_braces() {
+        let template = r#"println!("\{{}} \{{x}}", {{day}}); // }}"#;
+        assert_eq!(
+            render(template, &context()).unwrap(),
+            r#"println!("{{}} {{x}}", 7); // }}"#
+        );
+        assert!(render(r#"format!("{{}}")"#, &context()).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_placeholders() {
+        assert!(render("{{month}}", &context()).is_err());
+        assert!(render("{{day", &context()).is_err());
+        assert_eq!(
+            render("no placeholders", &context()).unwrap(),
+            "no placeholders"
+        );
+    }
+
+    #[test]
+    fn reads_titles() {
+        assert_eq!(
+            get_title("## --- Day 7: Camel Cards ---\n\nYour all-expenses-paid trip...").as_deref(),
+            Some("Day 7: Camel Cards")
+        );
+        assert_eq!(get_title("## --- Part Two ---"), None);
+    }
+}
@@ -0,0 +1,204 @@
+/// Polling file watcher used by `cargo solve --watch`.
+/// Changes are detected by comparing the modification times of the watched files between polls.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::template::{data_dir, ANSI_BOLD, ANSI_RESET};
+use crate::PuzzleId;
+
+/// Modification times of every watched file, in a stable order.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Snapshot(Vec<(PathBuf, Option<SystemTime>)>);
+
+/// Collects the files that affect a solution: its binary, the shared library and its inputs and examples.
+/// Binaries of other days are not watched.
+#[must_use]
+pub fn get_watched_files(puzzle: PuzzleId) -> Vec<PathBuf> {
+    let bin_dir = Path::new("src/bin");
+    let own_bin = bin_dir.join(format!("{puzzle}.rs"));
+
+    let mut files: Vec<PathBuf> = list_files(Path::new("src"))
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .filter(|path| !path.starts_with(bin_dir) || *path == own_bin)
+        .collect();
+
+    files.push(PathBuf::from(format!(
+        "{}/{}.txt",
+        data_dir(puzzle.year, "inputs"),
+        puzzle.day
+    )));
+
+    let day = puzzle.day.to_string();
+    files.extend(
+        list_files(Path::new(&data_dir(puzzle.year, "examples")))
+            .into_iter()
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&day))
+            }),
+    );
+
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Lists the files in a directory and its subdirectories. Missing directories yield no files.
+fn list_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .flat_map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                list_files(&path)
+            } else {
+                vec![path]
+            }
+        })
+        .collect()
+}
+
+impl Snapshot {
+    /// Reads the modification times of `files`. Missing files are recorded as well, so creating them counts as a change.
+    #[must_use]
+    pub fn take(files: Vec<PathBuf>) -> Self {
+        Self(
+            files
+                .into_iter()
+                .map(|path| {
+                    let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+                    (path, modified)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the files that were added, removed or modified since `previous`.
+    #[must_use]
+    pub fn changes(&self, previous: &Snapshot) -> Vec<PathBuf> {
+        let changed = self.0.iter().filter(|entry| !previous.0.contains(entry));
+        let removed = previous
+            .0
+            .iter()
+            .filter(|(path, _)| !self.0.iter().any(|(p, _)| p == path));
+
+        changed
+            .chain(removed)
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+/// Outcome of one test of a solution.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TestResult {
+    pub name: String,
+    pub passed: bool,
+}
+
+/// Reads the results from the output of the test harness, e.g. `test tests::test_part_one ... ok`.
+#[must_use]
+pub fn parse_test_output(output: &str) -> Vec<TestResult> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, outcome) = line.strip_prefix("test ")?.split_once(" ... ")?;
+            let passed = match outcome.trim() {
+                "ok" => true,
+                "FAILED" => false,
+                _ => return None,
+            };
+            Some(TestResult {
+                name: name.rsplit("::").next().unwrap_or(name).to_string(),
+                passed,
+            })
+        })
+        .collect()
+}
+
+/// Formats test results as a single line, e.g. `Examples: test_part_one ✔  test_part_two ✖`.
+#[must_use]
+pub fn format_test_results(results: &[TestResult]) -> String {
+    if results.is_empty() {
+        return format!("{ANSI_BOLD}Examples:{ANSI_RESET} no tests");
+    }
+
+    let results: Vec<String> = results
+        .iter()
+        .map(|r| format!("{} {}", r.name, if r.passed { "✔" } else { "✖" }))
+        .collect();
+
+    format!("{ANSI_BOLD}Examples:{ANSI_RESET} {}", results.join("  "))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_test_results, parse_test_output, Snapshot, TestResult};
+    use std::time::{Duration, SystemTime};
+    use std::{env, fs};
+
+    #[test]
+    fn parses_test_output() {
+        let output = [
+            "running 3 tests",
+            "test tests::test_part_one ... ok",
+            "test tests::test_part_two ... FAILED",
+            "test tests::test_slow ... ignored",
+            "",
+            "test result: FAILED. 1 passed; 1 failed; 1 ignored",
+        ]
+        .join("\n");
+
+        let results = parse_test_output(&output);
+        assert_eq!(
+            results,
+            vec![
+                TestResult {
+                    name: "test_part_one".into(),
+                    passed: true
+                },
+                TestResult {
+                    name: "test_part_two".into(),
+                    passed: false
+                },
+            ]
+        );
+        assert!(format_test_results(&results).ends_with("test_part_one ✔  test_part_two ✖"));
+    }
+
+    #[test]
+    fn detects_changes() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("01.txt");
+        let missing = dir.join("01-2.txt");
+        fs::write(&file, "1").unwrap();
+
+        let files = || vec![file.clone(), missing.clone()];
+        let before = Snapshot::take(files());
+        assert!(Snapshot::take(files()).changes(&before).is_empty());
+
+        fs::write(&missing, "2").unwrap();
+        let after = Snapshot::take(files());
+        assert_eq!(after.changes(&before), vec![missing.clone()]);
+
+        let later = SystemTime::now() + Duration::from_secs(5);
+        fs::File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert_eq!(Snapshot::take(files()).changes(&after), vec![file.clone()]);
+        assert_eq!(Snapshot::take(vec![]).changes(&after).len(), 2);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}